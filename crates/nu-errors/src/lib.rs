@@ -110,6 +110,10 @@ pub enum ArgumentError {
     MissingMandatoryPositional(String),
     /// A flag was found, and it should have been followed by a value, but no value was found
     MissingValueForName(String),
+    /// A flag was found that the command doesn't declare, along with the flags it does declare
+    UnknownFlag(String, Vec<String>),
+    /// A switch was followed by a value, but switches don't take one
+    UnexpectedValueForSwitch(String),
     /// A sequence of characters was found that was not syntactically valid (but would have
     /// been valid if the command was an external command)
     InvalidExternalWord,
@@ -133,6 +137,14 @@ impl PrettyDebug for ArgumentError {
                     + b::description(name)
                     + b::description("`")
             }
+            ArgumentError::UnknownFlag(flag, _) => {
+                b::description("unknown flag `") + b::description(flag) + b::description("`")
+            }
+            ArgumentError::UnexpectedValueForSwitch(name) => {
+                b::description("unexpected value for switch `")
+                    + b::description(name)
+                    + b::description("`")
+            }
             ArgumentError::InvalidExternalWord => b::description("invalid word"),
         }
     }
@@ -173,7 +185,7 @@ impl PrettyDebug for ShellError {
                         ")",
                     )
             }
-            ProximateShellError::MissingProperty { subpath, expr } => {
+            ProximateShellError::MissingProperty { subpath, expr, .. } => {
                 b::error("Missing Property")
                     + b::space()
                     + b::delimit(
@@ -302,14 +314,29 @@ impl ShellError {
     pub fn missing_property(
         subpath: Spanned<impl Into<String>>,
         expr: Spanned<impl Into<String>>,
+    ) -> ShellError {
+        ShellError::missing_property_with_suggestion(subpath, expr, None)
+    }
+
+    /// Like `missing_property`, with the name of an existing property to suggest in its place.
+    pub fn missing_property_with_suggestion(
+        subpath: Spanned<impl Into<String>>,
+        expr: Spanned<impl Into<String>>,
+        suggestion: Option<String>,
     ) -> ShellError {
         ProximateShellError::MissingProperty {
             subpath: subpath.map(|s| s.into()),
             expr: expr.map(|e| e.into()),
+            suggestion,
         }
         .start()
     }
 
+    /// Whether this error reports a property missing from the value it was looked up in.
+    pub fn is_missing_property(&self) -> bool {
+        matches!(self.error, ProximateShellError::MissingProperty { .. })
+    }
+
     pub fn invalid_integer_index(
         subpath: Spanned<impl Into<String>>,
         integer: impl Into<Span>,
@@ -459,6 +486,36 @@ impl ShellError {
                     ),
                 )
                 .with_label(Label::new_primary(command.span)),
+                ArgumentError::UnknownFlag(flag, valid) => Diagnostic::new(
+                    Severity::Error,
+                    format!(
+                        "Unknown flag {} for {}",
+                        Color::Black.bold().paint(flag),
+                        Color::Cyan.paint(&command.item)
+                    ),
+                )
+                .with_label(Label::new_primary(command.span).with_message(if valid.is_empty() {
+                    format!("{} takes no flags", command.item)
+                } else {
+                    format!(
+                        "valid flags are {}",
+                        valid
+                            .iter()
+                            .map(|flag| format!("--{}", flag))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                })),
+                ArgumentError::UnexpectedValueForSwitch(name) => Diagnostic::new(
+                    Severity::Error,
+                    format!(
+                        "{} doesn't take a value for switch {}{}",
+                        Color::Cyan.paint(&command.item),
+                        Color::Black.bold().paint("--"),
+                        Color::Black.bold().paint(name)
+                    ),
+                )
+                .with_label(Label::new_primary(command.span).with_message("unexpected value")),
             },
             ProximateShellError::TypeError {
                 expected,
@@ -512,18 +569,28 @@ impl ShellError {
             } => Diagnostic::new(Severity::Error, "Syntax Error")
                 .with_label(Label::new_primary(span).with_message(item)),
 
-            ProximateShellError::MissingProperty { subpath, expr, .. } => {
+            ProximateShellError::MissingProperty { subpath, expr, suggestion } => {
 
                 let mut diag = Diagnostic::new(Severity::Error, "Missing property");
 
                 if subpath.span == Span::unknown() {
-                    diag.message = format!("Missing property (for {})", subpath.item);
+                    diag.message = match suggestion {
+                        Some(suggestion) => format!(
+                            "Missing property (for {}), did you mean '{}'?",
+                            subpath.item, suggestion
+                        ),
+                        None => format!("Missing property (for {})", subpath.item),
+                    };
                 } else {
                     let subpath = Label::new_primary(subpath.span).with_message(subpath.item);
                     diag = diag.with_label(subpath);
 
                     if expr.span != Span::unknown() {
-                        let expr = Label::new_primary(expr.span).with_message(expr.item);
+                        let message = match suggestion {
+                            Some(suggestion) => format!("did you mean '{}'?", suggestion),
+                            None => expr.item,
+                        };
+                        let expr = Label::new_primary(expr.span).with_message(message);
                         diag = diag.with_label(expr)
                     }
 
@@ -533,17 +600,16 @@ impl ShellError {
             }
 
             ProximateShellError::InvalidIntegerIndex { subpath,integer } => {
-                let mut diag = Diagnostic::new(Severity::Error, "Invalid integer property");
+                let mut diag = Diagnostic::new(Severity::Error, "Invalid integer property")
+                    .with_label(Label::new_primary(integer).with_message("integer"));
 
                 if subpath.span == Span::unknown() {
                     diag.message = format!("Invalid integer property (for {})", subpath.item)
                 } else {
-                    let label = Label::new_primary(subpath.span).with_message(subpath.item);
+                    let label = Label::new_secondary(subpath.span).with_message(subpath.item);
                     diag = diag.with_label(label)
                 }
 
-                diag = diag.with_label(Label::new_secondary(integer).with_message("integer"));
-
                 diag
             }
 
@@ -674,6 +740,7 @@ pub enum ProximateShellError {
     MissingProperty {
         subpath: Spanned<String>,
         expr: Spanned<String>,
+        suggestion: Option<String>,
     },
     InvalidIntegerIndex {
         subpath: Spanned<String>,
@@ -232,6 +232,12 @@ impl Expression {
             .into_expr(span)
     }
 
+    pub fn compound_size(span: impl Into<Span>) -> Expression {
+        let span = span.into();
+
+        RawExpression::Literal(RawLiteral::CompoundSize.into_literal(span)).into_expr(span)
+    }
+
     pub fn synthetic_string(s: impl Into<String>) -> Expression {
         RawExpression::Synthetic(Synthetic::String(s.into())).into_unspanned_expr()
     }
@@ -265,10 +271,10 @@ impl Expression {
         let new_span = head.span.until(next.span);
 
         match item {
-            RawExpression::Path(path) => {
-                let (head, mut tail) = path.parts();
+            RawExpression::Path(mut path) => {
+                path.push_member(next);
 
-                tail.push(next);
+                let (head, tail) = path.parts();
                 Expression::path(head, tail, new_span)
             }
 
@@ -342,6 +348,7 @@ impl From<Spanned<Path>> for Expression {
 pub enum RawLiteral {
     Number(Number),
     Size(Number, Unit),
+    CompoundSize,
     String(Span),
     GlobPattern(String),
     ColumnPath(Vec<Member>),
@@ -367,7 +374,7 @@ impl ShellTypeName for Literal {
     fn type_name(&self) -> &'static str {
         match &self.literal {
             RawLiteral::Number(..) => "number",
-            RawLiteral::Size(..) => "size",
+            RawLiteral::Size(..) | RawLiteral::CompoundSize => "size",
             RawLiteral::String(..) => "string",
             RawLiteral::ColumnPath(..) => "column path",
             RawLiteral::Bare => "string",
@@ -381,6 +388,7 @@ impl PrettyDebugWithSource for Literal {
         match &self.literal {
             RawLiteral::Number(number) => number.pretty(),
             RawLiteral::Size(number, unit) => (number.pretty() + unit.pretty()).group(),
+            RawLiteral::CompoundSize => b::primitive(self.span.slice(source)),
             RawLiteral::String(string) => b::primitive(format!("{:?}", string.slice(source))),
             RawLiteral::GlobPattern(pattern) => b::typed("pattern", b::primitive(pattern)),
             RawLiteral::ColumnPath(path) => b::typed(
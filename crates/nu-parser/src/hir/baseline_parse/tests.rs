@@ -61,6 +61,187 @@ fn test_parse_path() {
     );
 }
 
+#[test]
+fn test_parse_compound_size() {
+    parse_tokens(AnyExpressionShape, vec![b::bare("1h30m")], |tokens| {
+        hir::Expression::compound_size(tokens[0].span())
+    });
+}
+
+#[test]
+fn test_parse_consecutive_blocks() {
+    let tokens = b::token_list(vec![
+        b::braced(vec![b::int(1)]),
+        b::sp(),
+        b::braced(vec![b::int(2)]),
+    ]);
+    let (tokens, source) = b::build(tokens);
+    let text = Text::from(source);
+
+    with_empty_context(&text, |context| {
+        let tokens = tokens.expect_list();
+        let mut iterator = TokensIterator::all(tokens.item, text.clone(), tokens.span);
+
+        let first = expand_expr(&SyntaxShape::Block, &mut iterator, &context).expect("first block");
+        let second =
+            expand_expr(&SyntaxShape::Block, &mut iterator, &context).expect("second block");
+
+        assert_ne!(first, second);
+        assert!(iterator.at_end());
+    })
+}
+
+#[test]
+fn test_parse_flags_around_positionals() {
+    let nth = |tokens: Vec<CurriedToken>, flag: usize, first: usize, second: usize| {
+        parse_tokens(ClassifiedCommandShape, tokens, |tokens| {
+            let bare = tokens[0].expect_bare();
+            let flag = tokens[flag].span();
+
+            let mut map = IndexMap::new();
+            map.insert(
+                "invert".to_string(),
+                NamedValue::PresentSwitch(Tag {
+                    span: Span::new(flag.start() + 2, flag.end()),
+                    anchor: None,
+                }),
+            );
+
+            ClassifiedCommand::Internal(InternalCommand::new(
+                "nth".to_string(),
+                Tag {
+                    span: bare,
+                    anchor: None,
+                },
+                hir::Call {
+                    head: Box::new(hir::RawExpression::Command(bare).into_expr(bare)),
+                    positional: Some(vec![
+                        hir::Expression::number(1, tokens[first].span()),
+                        hir::Expression::number(2, tokens[second].span()),
+                    ]),
+                    named: Some(NamedArguments { named: map }),
+                    span: bare.until(tokens[tokens.len() - 1].span()),
+                },
+            ))
+        });
+    };
+
+    nth(
+        vec![
+            b::bare("nth"),
+            b::sp(),
+            b::flag("invert"),
+            b::sp(),
+            b::int(1),
+            b::sp(),
+            b::int(2),
+        ],
+        2,
+        4,
+        6,
+    );
+    nth(
+        vec![
+            b::bare("nth"),
+            b::sp(),
+            b::int(1),
+            b::sp(),
+            b::int(2),
+            b::sp(),
+            b::flag("invert"),
+        ],
+        6,
+        2,
+        4,
+    );
+}
+
+#[test]
+fn test_parse_value_flag_between_positionals() {
+    parse_tokens(
+        ClassifiedCommandShape,
+        vec![
+            b::bare("echo"),
+            b::sp(),
+            b::int(1),
+            b::sp(),
+            b::flag("separator"),
+            b::sp(),
+            b::string(","),
+            b::sp(),
+            b::int(2),
+        ],
+        |tokens| {
+            let bare = tokens[0].expect_bare();
+            let (separator, _) = tokens[6].expect_string();
+
+            let mut map = IndexMap::new();
+            map.insert(
+                "separator".to_string(),
+                NamedValue::Value(hir::Expression::string(
+                    inner_string_span(separator),
+                    separator,
+                )),
+            );
+
+            ClassifiedCommand::Internal(InternalCommand::new(
+                "echo".to_string(),
+                Tag {
+                    span: bare,
+                    anchor: None,
+                },
+                hir::Call {
+                    head: Box::new(hir::RawExpression::Command(bare).into_expr(bare)),
+                    positional: Some(vec![
+                        hir::Expression::number(1, tokens[2].span()),
+                        hir::Expression::number(2, tokens[8].span()),
+                    ]),
+                    named: Some(NamedArguments { named: map }),
+                    span: bare.until(tokens[8].span()),
+                },
+            ))
+        },
+    );
+}
+
+#[test]
+fn test_parse_switch_followed_by_a_value() {
+    let (err, tokens) = parse_tokens_error(
+        ClassifiedCommandShape,
+        vec![
+            b::bare("nth"),
+            b::sp(),
+            b::int(1),
+            b::sp(),
+            b::flag("invert"),
+            b::sp(),
+            b::int(2),
+        ],
+    );
+    let diagnostic = err.to_diagnostic();
+
+    assert!(diagnostic.message.contains("doesn't take a value"));
+    assert_eq!(diagnostic.labels[0].span, tokens[6].span());
+}
+
+#[test]
+fn test_parse_unknown_flag() {
+    let unknown = |flag: CurriedToken, label: &str| {
+        let (err, tokens) = parse_tokens_error(
+            ClassifiedCommandShape,
+            vec![b::bare("nth"), b::sp(), b::int(1), b::sp(), flag],
+        );
+        let diagnostic = err.to_diagnostic();
+
+        assert!(diagnostic.message.contains("Unknown flag"));
+        assert_eq!(diagnostic.labels[0].message, Some(label.to_string()));
+        assert_eq!(diagnostic.labels[0].span, tokens[4].span());
+    };
+
+    unknown(b::flag("invret"), "valid flags are --invert");
+    unknown(b::shorthand("i"), "valid flags are --invert");
+}
+
 #[test]
 fn test_parse_command() {
     parse_tokens(
@@ -90,6 +271,46 @@ fn test_parse_command() {
     );
 }
 
+#[test]
+fn test_parse_short_switch() {
+    let exit = |flag: CurriedToken, prefix: usize| {
+        parse_tokens(
+            ClassifiedCommandShape,
+            vec![b::bare("exit"), b::sp(), flag],
+            |tokens| {
+                let bare = tokens[0].expect_bare();
+                let flag = tokens[2].span();
+
+                let mut map = IndexMap::new();
+                map.insert(
+                    "now".to_string(),
+                    NamedValue::PresentSwitch(Tag {
+                        span: Span::new(flag.start() + prefix, flag.end()),
+                        anchor: None,
+                    }),
+                );
+
+                ClassifiedCommand::Internal(InternalCommand::new(
+                    "exit".to_string(),
+                    Tag {
+                        span: bare,
+                        anchor: None,
+                    },
+                    hir::Call {
+                        head: Box::new(hir::RawExpression::Command(bare).into_expr(bare)),
+                        positional: None,
+                        named: Some(NamedArguments { named: map }),
+                        span: bare.until(flag),
+                    },
+                ))
+            },
+        );
+    };
+
+    exit(b::shorthand("n"), 1);
+    exit(b::flag("now"), 2);
+}
+
 #[derive(new)]
 struct TestRegistry {
     #[new(default)]
@@ -113,6 +334,22 @@ impl SignatureRegistry for TestRegistry {
 
 fn with_empty_context(source: &Text, callback: impl FnOnce(ExpandContext)) {
     let mut registry = TestRegistry::new();
+    registry.insert(
+        "nth",
+        Signature::build("nth")
+            .rest(SyntaxShape::Int, "the row numbers to select")
+            .switch("invert", "return every row except the selected ones"),
+    );
+    registry.insert(
+        "echo",
+        Signature::build("echo")
+            .rest(SyntaxShape::Int, "the values to echo")
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "text to join the values with",
+            ),
+    );
     registry.insert(
         "ls",
         Signature::build("ls")
@@ -123,6 +360,10 @@ fn with_empty_context(source: &Text, callback: impl FnOnce(ExpandContext)) {
             )
             .switch("full", "list all available columns for each entry"),
     );
+    registry.insert(
+        "exit",
+        Signature::build("exit").switch_short("now", "exit out of the shell immediately", 'n'),
+    );
 
     callback(ExpandContext::new(Box::new(registry), source, None))
 }
@@ -154,6 +395,31 @@ fn parse_tokens<T: Eq + HasSpan + Clone + Debug + 'static>(
     })
 }
 
+fn parse_tokens_error<T: Eq + HasSpan + Clone + Debug + 'static>(
+    shape: impl ExpandSyntax<Output = T>,
+    tokens: Vec<CurriedToken>,
+) -> (ShellError, Vec<TokenNode>) {
+    let tokens = b::token_list(tokens);
+    let (tokens, source) = b::build(tokens);
+    let text = Text::from(source);
+    let mut error = None;
+
+    with_empty_context(&text, |context| {
+        let tokens = tokens.expect_list();
+        let mut iterator = TokensIterator::all(tokens.item, text.clone(), tokens.span);
+
+        match expand_syntax(&shape, &mut iterator, &context) {
+            Ok(expr) => panic!("Parse succeeded unexpectedly: {:?}", expr),
+            Err(err) => error = Some(err.into()),
+        }
+    });
+
+    (
+        error.expect("parse error"),
+        tokens.expect_list().item.to_vec(),
+    )
+}
+
 fn inner_string_span(span: Span) -> Span {
     Span::new(span.start() + 1, span.end() - 1)
 }
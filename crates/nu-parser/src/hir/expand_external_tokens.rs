@@ -163,7 +163,9 @@ impl ExpandExpression for ExternalHeadShape {
         Ok(match &atom.unspanned {
             UnspannedAtomicToken::Eof { .. } => unreachable!("ExpansionRule doesn't allow EOF"),
             UnspannedAtomicToken::Error { .. } => unreachable!("ExpansionRule doesn't allow Error"),
-            UnspannedAtomicToken::Size { .. } => unreachable!("ExpansionRule treats size as word"),
+            UnspannedAtomicToken::Size { .. } | UnspannedAtomicToken::CompoundSize { .. } => {
+                unreachable!("ExpansionRule treats size as word")
+            }
             UnspannedAtomicToken::Whitespace { .. } => {
                 unreachable!("ExpansionRule doesn't allow Whitespace")
             }
@@ -232,7 +234,9 @@ impl ExpandExpression for ExternalContinuationShape {
             UnspannedAtomicToken::Number { number } => {
                 Expression::number(number.to_number(context.source()), span)
             }
-            UnspannedAtomicToken::Size { .. } => unreachable!("ExpansionRule treats size as word"),
+            UnspannedAtomicToken::Size { .. } | UnspannedAtomicToken::CompoundSize { .. } => {
+                unreachable!("ExpansionRule treats size as word")
+            }
             UnspannedAtomicToken::ExternalCommand { .. } => {
                 unreachable!("ExpansionRule doesn't allow ExternalCommand")
             }
@@ -38,4 +38,37 @@ impl Path {
     pub(crate) fn parts(self) -> (Expression, Vec<PathMember>) {
         (self.head, self.tail)
     }
+
+    pub(crate) fn push_member(&mut self, member: PathMember) {
+        self.tail.push(member);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Path;
+    use crate::hir::Expression;
+    use nu_protocol::PathMember;
+    use nu_source::Span;
+
+    #[test]
+    fn pushes_members_onto_the_tail_in_order() {
+        let head = Expression::it_variable(Span::new(0, 3), Span::new(0, 3));
+        let mut path = Path::new(head.clone(), vec![]);
+
+        path.push_member(PathMember::string("package", Span::new(4, 11)));
+        path.push_member(PathMember::int(0, Span::new(12, 13)));
+
+        assert_eq!(path.head(), &head);
+        assert_eq!(
+            path.parts(),
+            (
+                head,
+                vec![
+                    PathMember::string("package", Span::new(4, 11)),
+                    PathMember::int(0, Span::new(12, 13))
+                ]
+            )
+        );
+    }
 }
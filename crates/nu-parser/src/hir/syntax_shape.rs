@@ -35,7 +35,7 @@ pub(crate) use self::expression::list::{BackoffColoringMode, ExpressionListShape
 pub(crate) use self::expression::number::{IntShape, NumberShape};
 pub(crate) use self::expression::pattern::{BarePatternShape, PatternShape};
 pub(crate) use self::expression::string::StringShape;
-pub(crate) use self::expression::unit::{UnitShape, UnitSyntax};
+pub(crate) use self::expression::unit::{CompoundUnitShape, UnitShape, UnitSyntax};
 pub(crate) use self::expression::variable_path::{
     ColorableDotShape, ColumnPathShape, ColumnPathSyntax, DotShape, ExpressionContinuation,
     ExpressionContinuationShape, Member, MemberShape, PathTailShape, PathTailSyntax,
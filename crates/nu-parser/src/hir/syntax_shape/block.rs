@@ -71,13 +71,15 @@ impl ExpandExpression for AnyBlockShape {
         token_nodes: &mut TokensIterator<'_>,
         context: &ExpandContext,
     ) -> Result<hir::Expression, ParseError> {
-        let block = token_nodes.peek_non_ws().not_eof("block")?;
+        let peeked = token_nodes.peek_non_ws().not_eof("block")?;
 
         // is it just a block?
-        let block = block.node.as_block();
+        let block = peeked.node.as_block();
 
         match block {
             Some((block, _tags)) => {
+                peeked.commit();
+
                 let mut iterator =
                     TokensIterator::new(&block.item, block.span, context.source.clone(), false);
 
@@ -1,7 +1,7 @@
 use crate::hir::syntax_shape::FlatShape;
 use crate::hir::syntax_shape::{
     expand_syntax, expression::expand_file_path, parse_single_node, BarePathShape,
-    BarePatternShape, ExpandContext, UnitShape, UnitSyntax,
+    BarePatternShape, CompoundUnitShape, ExpandContext, UnitShape, UnitSyntax,
 };
 use crate::parse::operator::EvaluationOperator;
 use crate::parse::token_tree::{DelimitedNode, Delimiter, TokenNode};
@@ -32,6 +32,9 @@ pub enum UnspannedAtomicToken<'tokens> {
         number: RawNumber,
         unit: Spanned<Unit>,
     },
+    CompoundSize {
+        text: Span,
+    },
     String {
         body: Span,
     },
@@ -113,7 +116,7 @@ impl<'tokens> ShellTypeName for UnspannedAtomicToken<'tokens> {
             UnspannedAtomicToken::Dot { .. } => "dot",
             UnspannedAtomicToken::DotDot { .. } => "dotdot",
             UnspannedAtomicToken::Number { .. } => "number",
-            UnspannedAtomicToken::Size { .. } => "size",
+            UnspannedAtomicToken::Size { .. } | UnspannedAtomicToken::CompoundSize { .. } => "size",
             UnspannedAtomicToken::String { .. } => "string",
             UnspannedAtomicToken::ItVariable { .. } => "$it",
             UnspannedAtomicToken::Variable { .. } => "variable",
@@ -180,6 +183,7 @@ impl<'tokens> AtomicToken<'tokens> {
             UnspannedAtomicToken::Size { number, unit } => {
                 Expression::size(number.to_number(context.source), **unit, self.span)
             }
+            UnspannedAtomicToken::CompoundSize { .. } => Expression::compound_size(self.span),
             UnspannedAtomicToken::String { body } => Expression::string(*body, self.span),
             UnspannedAtomicToken::ItVariable { name } => Expression::it_variable(*name, self.span),
             UnspannedAtomicToken::Variable { name } => Expression::variable(*name, self.span),
@@ -229,6 +233,9 @@ impl<'tokens> AtomicToken<'tokens> {
                     .spanned(self.span),
                 );
             }
+            UnspannedAtomicToken::CompoundSize { .. } => {
+                return shapes.push(FlatShape::CompoundSize.spanned(self.span))
+            }
             UnspannedAtomicToken::String { .. } => {
                 return shapes.push(FlatShape::String.spanned(self.span))
             }
@@ -279,6 +286,7 @@ impl PrettyDebugWithSource for AtomicToken<'_> {
             UnspannedAtomicToken::Size { number, unit } => {
                 number.pretty_debug(source) + b::keyword(unit.span.slice(source))
             }
+            UnspannedAtomicToken::CompoundSize { text } => b::keyword(text.slice(source)),
             UnspannedAtomicToken::String { body } => b::primitive(body.slice(source)),
             UnspannedAtomicToken::ItVariable { .. } | UnspannedAtomicToken::Variable { .. } => {
                 b::keyword(self.span.slice(source))
@@ -555,6 +563,17 @@ fn expand_atom_inner<'me, 'content>(
         },
     }
 
+    // A word made of several sizes, like `1h30m`, is a single size too
+    match rule.treat_size_as_word {
+        true => {}
+        false => match expand_syntax(&CompoundUnitShape, token_nodes, context) {
+            Err(_) => {}
+            Ok(text) => {
+                return Ok(UnspannedAtomicToken::CompoundSize { text }.into_atomic_token(text))
+            }
+        },
+    }
+
     match rule.separate_members {
         false => {}
         true => {
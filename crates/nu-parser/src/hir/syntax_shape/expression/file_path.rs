@@ -41,7 +41,8 @@ impl FallibleColorSyntax for FilePathShape {
             UnspannedAtomicToken::Word { .. }
             | UnspannedAtomicToken::String { .. }
             | UnspannedAtomicToken::Number { .. }
-            | UnspannedAtomicToken::Size { .. } => {
+            | UnspannedAtomicToken::Size { .. }
+            | UnspannedAtomicToken::CompoundSize { .. } => {
                 token_nodes.color_shape(FlatShape::Path.spanned(atom.span));
             }
 
@@ -77,7 +78,9 @@ impl ExpandExpression for FilePathShape {
                 return Ok(hir::Expression::file_path(path, atom.span));
             }
 
-            UnspannedAtomicToken::Number { .. } | UnspannedAtomicToken::Size { .. } => {
+            UnspannedAtomicToken::Number { .. }
+            | UnspannedAtomicToken::Size { .. }
+            | UnspannedAtomicToken::CompoundSize { .. } => {
                 let path = atom.span.slice(context.source);
                 return Ok(hir::Expression::file_path(path, atom.span));
             }
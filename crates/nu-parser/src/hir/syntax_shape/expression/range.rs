@@ -71,7 +71,7 @@ impl FallibleColorSyntax for RangeShape {
 }
 
 #[derive(Debug, Copy, Clone)]
-struct DotDotShape;
+pub(crate) struct DotDotShape;
 
 impl FallibleColorSyntax for DotDotShape {
     type Info = ();
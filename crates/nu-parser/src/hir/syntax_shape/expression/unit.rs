@@ -4,13 +4,14 @@ use crate::parse::tokens::Token;
 use crate::parse::tokens::UnspannedToken;
 use crate::parse::unit::Unit;
 use crate::{hir::TokensIterator, TokenNode};
-use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::digit1;
-use nom::combinator::{all_consuming, opt, value};
+use nom::combinator::opt;
+use nom::error::ErrorKind;
 use nom::IResult;
 use nu_errors::ParseError;
 use nu_source::{b, DebugDocBuilder, HasSpan, PrettyDebugWithSource, Span, Spanned, SpannedItem};
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 pub struct UnitSyntax {
@@ -73,6 +74,41 @@ impl ExpandSyntax for UnitShape {
     }
 }
 
+/// A literal made of several number and unit pairs, such as `1h30m`
+#[derive(Debug, Copy, Clone)]
+pub struct CompoundUnitShape;
+
+impl ExpandSyntax for CompoundUnitShape {
+    type Output = Span;
+
+    fn name(&self) -> &'static str {
+        "compound unit"
+    }
+
+    fn expand_syntax<'a, 'b>(
+        &self,
+        token_nodes: &'b mut TokensIterator<'a>,
+        context: &ExpandContext,
+    ) -> Result<Span, ParseError> {
+        let peeked = token_nodes.peek_any().not_eof("compound unit")?;
+
+        let span = match peeked.node {
+            TokenNode::Token(Token {
+                unspanned: UnspannedToken::Bare,
+                span,
+            }) => *span,
+            _ => return Err(peeked.type_error("compound unit")),
+        };
+
+        if Unit::parse_compound(span.slice(context.source).spanned(span)).is_err() {
+            return Err(ParseError::mismatch("compound unit", "word".spanned(span)));
+        }
+
+        peeked.commit();
+        Ok(span)
+    }
+}
+
 fn unit_size(input: &str, bare_span: Span) -> IResult<&str, (RawNumber, Spanned<Unit>)> {
     let (input, digits) = digit1(input)?;
 
@@ -99,21 +135,13 @@ fn unit_size(input: &str, bare_span: Span) -> IResult<&str, (RawNumber, Spanned<
         ),
     };
 
-    let (input, unit) = all_consuming(alt((
-        value(Unit::Byte, alt((tag("B"), tag("b")))),
-        value(Unit::Kilobyte, alt((tag("KB"), tag("kb"), tag("Kb")))),
-        value(Unit::Megabyte, alt((tag("MB"), tag("mb"), tag("Mb")))),
-        value(Unit::Gigabyte, alt((tag("GB"), tag("gb"), tag("Gb")))),
-        value(Unit::Terabyte, alt((tag("TB"), tag("tb"), tag("Tb")))),
-        value(Unit::Petabyte, alt((tag("PB"), tag("pb"), tag("Pb")))),
-        value(Unit::Second, tag("s")),
-        value(Unit::Minute, tag("m")),
-        value(Unit::Hour, tag("h")),
-        value(Unit::Day, tag("d")),
-        value(Unit::Week, tag("w")),
-        value(Unit::Month, tag("M")),
-        value(Unit::Year, tag("y")),
-    )))(input)?;
+    // Everything after the number is the suffix, and `Unit::from_str` owns the valid spellings
+    let unit = match Unit::from_str(input) {
+        Ok(unit) => unit,
+        Err(()) => return Err(nom::Err::Error((input, ErrorKind::Tag))),
+    };
+
+    let input = &input[input.len()..];
 
     let start_span = number.span().end();
 
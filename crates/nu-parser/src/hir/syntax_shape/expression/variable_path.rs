@@ -1,11 +1,14 @@
+use crate::hir::syntax_shape::expression::range::DotDotShape;
 use crate::hir::syntax_shape::{
     color_fallible_syntax, color_fallible_syntax_with, expand_atom, expand_expr, expand_syntax,
     parse_single_node, AnyExpressionShape, BareShape, ExpandContext, ExpandExpression,
     ExpandSyntax, ExpansionRule, FallibleColorSyntax, FlatShape, ParseError, Peeked, SkipSyntax,
     StringShape, TestSyntax, UnspannedAtomicToken, WhitespaceShape,
 };
-use crate::parse::tokens::{RawNumber, UnspannedToken};
-use crate::{hir, hir::Expression, hir::TokensIterator, CompareOperator, EvaluationOperator};
+use crate::parse::tokens::{RawNumber, Token, UnspannedToken};
+use crate::{
+    hir, hir::Expression, hir::TokensIterator, CompareOperator, EvaluationOperator, TokenNode,
+};
 use nu_errors::ShellError;
 use nu_protocol::{PathMember, ShellTypeName};
 use nu_source::{
@@ -387,6 +390,9 @@ pub enum Member {
     String(/* outer */ Span, /* inner */ Span),
     Int(BigInt, Span),
     Bare(Span),
+    Wildcard(Span),
+    /// The rows from the first integer up to (but not including) the second, with their spans
+    Range(BigInt, Span, BigInt, Span),
 }
 
 impl ShellTypeName for Member {
@@ -395,6 +401,8 @@ impl ShellTypeName for Member {
             Member::String(_, _) => "string",
             Member::Int(_, _) => "integer",
             Member::Bare(_) => "word",
+            Member::Wildcard(_) => "wildcard",
+            Member::Range(..) => "range",
         }
     }
 }
@@ -405,6 +413,10 @@ impl Member {
             Member::String(outer, inner) => PathMember::string(inner.slice(source), *outer),
             Member::Int(int, span) => PathMember::int(int.clone(), *span),
             Member::Bare(span) => PathMember::string(span.slice(source), *span),
+            Member::Wildcard(span) => PathMember::wildcard(*span),
+            Member::Range(from, from_span, to, to_span) => {
+                PathMember::range(from.clone(), to.clone(), from_span.until(*to_span))
+            }
         }
     }
 }
@@ -414,7 +426,8 @@ impl PrettyDebugWithSource for Member {
         match self {
             Member::String(outer, _) => b::value(outer.slice(source)),
             Member::Int(int, _) => b::value(format!("{}", int)),
-            Member::Bare(span) => b::value(span.slice(source)),
+            Member::Bare(span) | Member::Wildcard(span) => b::value(span.slice(source)),
+            Member::Range(from, _, to, _) => b::value(format!("{}..{}", from, to)),
         }
     }
 }
@@ -425,6 +438,8 @@ impl HasSpan for Member {
             Member::String(outer, ..) => *outer,
             Member::Int(_, int) => *int,
             Member::Bare(name) => *name,
+            Member::Wildcard(wildcard) => *wildcard,
+            Member::Range(_, from, _, to) => from.until(*to),
         }
     }
 }
@@ -434,7 +449,12 @@ impl Member {
         match self {
             Member::String(outer, inner) => hir::Expression::string(*inner, *outer),
             Member::Int(number, span) => hir::Expression::number(number.clone(), *span),
-            Member::Bare(span) => hir::Expression::string(*span, *span),
+            Member::Bare(span) | Member::Wildcard(span) => hir::Expression::string(*span, *span),
+            Member::Range(from, from_span, to, to_span) => hir::Expression::range(
+                hir::Expression::number(from.clone(), *from_span),
+                Span::new(from_span.end(), to_span.start()),
+                hir::Expression::number(to.clone(), *to_span),
+            ),
         }
     }
 
@@ -443,6 +463,8 @@ impl Member {
             Member::String(outer, _inner) => *outer,
             Member::Int(_, span) => *span,
             Member::Bare(span) => *span,
+            Member::Wildcard(span) => *span,
+            Member::Range(_, from, _, to) => from.until(*to),
         }
     }
 }
@@ -650,7 +672,20 @@ impl FallibleColorSyntax for MemberShape {
             color_fallible_syntax_with(&BareShape, &FlatShape::BareMember, token_nodes, context);
 
         match bare {
-            Ok(_) => return Ok(()),
+            Ok(_) => {
+                // A member followed by `..` and another member is a range of rows
+                let _ = token_nodes.atomic(|token_nodes| {
+                    color_fallible_syntax(&DotDotShape, token_nodes, context)?;
+                    color_fallible_syntax_with(
+                        &BareShape,
+                        &FlatShape::BareMember,
+                        token_nodes,
+                        context,
+                    )
+                });
+
+                return Ok(());
+            }
             Err(_) => {
                 // If we don't have a bare word, we'll look for a string
             }
@@ -710,6 +745,56 @@ impl ExpandSyntax for IntMemberShape {
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+struct RangeMemberShape;
+
+impl ExpandSyntax for RangeMemberShape {
+    type Output = Member;
+
+    fn name(&self) -> &'static str {
+        "range member"
+    }
+
+    fn expand_syntax<'a, 'b>(
+        &self,
+        token_nodes: &'b mut TokensIterator<'a>,
+        context: &ExpandContext,
+    ) -> Result<Self::Output, ParseError> {
+        token_nodes.atomic_parse(|token_nodes| {
+            let from = expand_syntax(&IntMemberShape, token_nodes, context)?;
+
+            let dotdot = expand_atom(
+                token_nodes,
+                "..",
+                context,
+                ExpansionRule::new().allow_eval_operator(),
+            )?;
+
+            match dotdot.unspanned {
+                UnspannedAtomicToken::DotDot { .. } => {}
+                other => {
+                    return Err(ParseError::mismatch(
+                        "..",
+                        other.type_name().spanned(dotdot.span),
+                    ))
+                }
+            }
+
+            let to = expand_syntax(&IntMemberShape, token_nodes, context)?;
+
+            match (from, to) {
+                (Member::Int(from, from_span), Member::Int(to, to_span)) => {
+                    Ok(Member::Range(from, from_span, to, to_span))
+                }
+                (_, to) => Err(ParseError::mismatch(
+                    "integer member",
+                    to.type_name().spanned(to.span()),
+                )),
+            }
+        })
+    }
+}
+
 impl ExpandSyntax for MemberShape {
     type Output = Member;
 
@@ -722,6 +807,10 @@ impl ExpandSyntax for MemberShape {
         token_nodes: &mut TokensIterator<'_>,
         context: &ExpandContext,
     ) -> Result<Member, ParseError> {
+        if let Ok(range) = expand_syntax(&RangeMemberShape, token_nodes, context) {
+            return Ok(range);
+        }
+
         if let Ok(int) = expand_syntax(&IntMemberShape, token_nodes, context) {
             return Ok(int);
         }
@@ -751,10 +840,38 @@ impl ExpandSyntax for MemberShape {
             return Ok(Member::String(outer, inner));
         }
 
+        let wildcard = WildcardMemberShape.test(token_nodes, context);
+
+        if let Some(peeked) = wildcard {
+            let node = peeked.not_eof("column")?.commit();
+            return Ok(Member::Wildcard(node.span()));
+        }
+
         Err(token_nodes.peek_any().type_error("column"))
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+struct WildcardMemberShape;
+
+impl TestSyntax for WildcardMemberShape {
+    fn test<'a, 'b>(
+        &self,
+        token_nodes: &'b mut TokensIterator<'a>,
+        context: &ExpandContext,
+    ) -> Option<Peeked<'a, 'b>> {
+        let peeked = token_nodes.peek_any();
+
+        match peeked.node {
+            Some(TokenNode::Token(Token {
+                unspanned: UnspannedToken::GlobPattern,
+                span,
+            })) if span.slice(context.source) == "*" => Some(peeked),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct DotShape;
 
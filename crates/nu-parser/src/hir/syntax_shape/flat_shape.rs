@@ -32,6 +32,7 @@ pub enum FlatShape {
     Error,
     Comment,
     Size { number: Span, unit: Span },
+    CompoundSize,
 }
 
 impl FlatShape {
@@ -503,6 +503,41 @@ impl<'content> TokensIterator<'content> {
         self.state.seen.insert(position);
     }
 
+    /// Remove the whitespace in front of an extracted token, so that the tokens on either side
+    /// of it are still separated by a single whitespace
+    pub fn remove_whitespace_before(&mut self, position: usize) {
+        let state = &mut self.state;
+
+        if position == 0 || state.seen.contains(&(position - 1)) {
+            return;
+        }
+
+        if state.tokens[position - 1].is_whitespace() {
+            state.seen.insert(position - 1);
+        }
+    }
+
+    /// The closest token before `position` that hasn't been seen yet, skipping whitespace
+    pub fn unseen_before(&self, position: usize) -> Option<&'content TokenNode> {
+        let state = &self.state;
+
+        (0..position)
+            .rev()
+            .filter(|i| !state.seen.contains(i))
+            .map(|i| &state.tokens[i])
+            .find(|token| !token.is_whitespace())
+    }
+
+    /// The closest token after `position` that hasn't been seen yet, skipping whitespace
+    pub fn unseen_after(&self, position: usize) -> Option<&'content TokenNode> {
+        let state = &self.state;
+
+        (position + 1..state.tokens.len())
+            .filter(|i| !state.seen.contains(i))
+            .map(|i| &state.tokens[i])
+            .find(|token| !token.is_whitespace())
+    }
+
     pub fn at_end(&self) -> bool {
         peek(self, self.state.skip_ws).is_none()
     }
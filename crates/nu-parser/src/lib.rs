@@ -18,6 +18,7 @@ pub use crate::parse::parser::Number;
 pub use crate::parse::parser::{module, pipeline};
 pub use crate::parse::token_tree::{Delimiter, TokenNode};
 pub use crate::parse::token_tree_builder::TokenTreeBuilder;
+pub use crate::parse::unit::{Unit, UnitKind};
 
 use nu_errors::ShellError;
 use nu_source::nom_input;
@@ -21,6 +21,7 @@ use nom::*;
 use nom::{AsBytes, FindSubstring, IResult, InputLength, InputTake, Slice};
 use nom_locate::{position, LocatedSpanEx};
 use nom_tracable::{tracable_parser, HasTracableInfo, TracableInfo};
+use nu_errors::{ExpectedRange, ShellError};
 use nu_protocol::{Primitive, UntaggedValue};
 use nu_source::{
     b, nom_input, DebugDocBuilder, HasSpan, NomSpan, PrettyDebug, PrettyDebugWithSource, Span,
@@ -28,7 +29,7 @@ use nu_source::{
 };
 use num_bigint::BigInt;
 use num_traits::identities::Zero;
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::str::FromStr;
@@ -188,6 +189,25 @@ impl std::ops::Mul<u32> for Number {
     }
 }
 
+impl Number {
+    /// Narrows the number to a `u64`, with a range error for negative numbers and numbers
+    /// above `u64::MAX`. Decimals are truncated towards zero.
+    pub fn to_u64_checked(&self, span: Span) -> Result<u64, ShellError> {
+        let converted = match self {
+            Number::Int(int) => int.to_u64(),
+            Number::Decimal(decimal) => decimal.to_u64(),
+        };
+
+        converted.ok_or_else(|| {
+            ShellError::range_error(
+                ExpectedRange::U64,
+                &"bigger number".spanned(span),
+                "converting to an unsigned 64-bit integer",
+            )
+        })
+    }
+}
+
 impl Into<Number> for BigDecimal {
     fn into(self) -> Number {
         Number::Decimal(self)
@@ -535,7 +555,10 @@ fn tight<'a>(
         let (input, head) = parser(input)?;
         result.extend(head);
 
-        let (input, tail) = opt(alt((many1(range_continuation), many1(dot_member))))(input)?;
+        let (input, tail) = opt(alt((
+            many1(range_continuation),
+            many1(alt((dot_member, member_range))),
+        )))(input)?;
 
         let next_char = &input.fragment.chars().nth(0);
 
@@ -786,7 +809,7 @@ pub fn delimited_brace(input: NomSpan) -> IResult<NomSpan, TokenNode> {
 
     Ok((
         input,
-        TokenTreeBuilder::spanned_square(tokens.item, (left, right), tokens.span),
+        TokenTreeBuilder::spanned_brace(tokens.item, (left, right), tokens.span),
     ))
 }
 
@@ -824,9 +847,29 @@ pub fn dot_member(input: NomSpan) -> IResult<NomSpan, Vec<TokenNode>> {
     Ok((input, vec![dot_result, member_result]))
 }
 
+#[tracable_parser]
+pub fn member_range(input: NomSpan) -> IResult<NomSpan, Vec<TokenNode>> {
+    let (input, dotdot_result) = dotdot(input)?;
+    let (input, member_result) = alt((number, member))(input)?;
+
+    Ok((input, vec![dotdot_result, member_result]))
+}
+
 #[tracable_parser]
 pub fn any_member(input: NomSpan) -> IResult<NomSpan, TokenNode> {
-    alt((number, string, member))(input)
+    alt((number, string, member, wildcard_member))(input)
+}
+
+#[tracable_parser]
+pub fn wildcard_member(input: NomSpan) -> IResult<NomSpan, TokenNode> {
+    let start = input.offset;
+    let (input, _) = tag("*")(input)?;
+    let end = input.offset;
+
+    Ok((
+        input,
+        TokenTreeBuilder::spanned_pattern(Span::new(start, end)),
+    ))
 }
 
 #[tracable_parser]
@@ -1308,6 +1351,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_delimited_brace() {
+        equal_tokens! {
+            <nodes>
+            "{abc}" -> b::token_list(vec![b::braced(vec![b::bare("abc")])])
+        }
+
+        equal_tokens! {
+            <nodes>
+            "{  abc def }" -> b::token_list(vec![b::braced(vec![b::ws("  "), b::bare("abc"), b::sp(), b::bare("def"), b::sp()])])
+        }
+    }
+
     #[test]
     fn test_range() {
         let _ = pretty_env_logger::try_init();
@@ -1346,6 +1402,16 @@ mod tests {
             <nodes>
             r#"( hello )."world""# -> b::token_list(vec![b::parens(vec![b::sp(), b::bare("hello"), b::sp()]), b::dot(), b::string("world")])
         }
+
+        equal_tokens! {
+            <nodes>
+            "$it.items.*.name" -> b::token_list(vec![b::var("it"), b::dot(), b::bare("items"), b::dot(), b::pattern("*"), b::dot(), b::bare("name")])
+        }
+
+        equal_tokens! {
+            <nodes>
+            "$it.items.1..3.name" -> b::token_list(vec![b::var("it"), b::dot(), b::bare("items"), b::dot(), b::int(1), b::dotdot(), b::bare("3"), b::dot(), b::bare("name")])
+        }
     }
 
     #[test]
@@ -1617,6 +1683,29 @@ mod tests {
     //     )
     // }
 
+    #[test]
+    fn numbers_narrow_to_u64_within_range() {
+        let span = Span::unknown();
+
+        assert_eq!(Number::from(0).to_u64_checked(span), Ok(0));
+        assert_eq!(
+            Number::from(u64::MAX).to_u64_checked(span),
+            Ok(u64::MAX)
+        );
+        assert_eq!(Number::from(2.5).to_u64_checked(span), Ok(2));
+    }
+
+    #[test]
+    fn numbers_outside_u64_are_range_errors() {
+        let span = Span::unknown();
+        let too_big = Number::from(u64::MAX) * Number::from(2);
+
+        assert!(too_big.to_u64_checked(span).is_err());
+        assert!((too_big * Number::from(0.5)).to_u64_checked(span).is_ok());
+        assert!(Number::from(-1).to_u64_checked(span).is_err());
+        assert!(Number::from(1e30).to_u64_checked(span).is_err());
+    }
+
     fn apply(
         f: impl Fn(NomSpan) -> Result<(NomSpan, TokenNode), nom::Err<(NomSpan, nom::error::ErrorKind)>>,
         desc: &str,
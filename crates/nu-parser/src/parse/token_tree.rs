@@ -292,6 +292,28 @@ impl TokenNode {
         }
     }
 
+    /// Matches the flag `value`, or its single character alias `short` written as `-short`
+    pub(crate) fn as_flag_or_short(
+        &self,
+        value: &str,
+        short: Option<char>,
+        source: &Text,
+    ) -> Option<Flag> {
+        if let Some(flag) = self.as_flag(value, source) {
+            return Some(flag);
+        }
+
+        match (self, short) {
+            (TokenNode::Flag(flag @ Flag { .. }), Some(short))
+                if flag.kind == FlagKind::Shorthand
+                    && flag.name().slice(source) == short.encode_utf8(&mut [0; 4]) =>
+            {
+                Some(*flag)
+            }
+            _ => None,
+        }
+    }
+
     pub fn as_pipeline(&self) -> Result<Pipeline, ParseError> {
         match self {
             TokenNode::Pipeline(pipeline) => Ok(pipeline.clone()),
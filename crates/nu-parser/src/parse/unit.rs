@@ -1,9 +1,14 @@
 use crate::parse::parser::Number;
-use nu_protocol::{Primitive, UntaggedValue};
-use nu_source::{b, DebugDocBuilder, PrettyDebug};
-use num_traits::ToPrimitive;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use nu_errors::{ExpectedRange, ShellError};
+use nu_protocol::{Primitive, ShellTypeName, UntaggedValue};
+use nu_source::{b, DebugDocBuilder, PrettyDebug, Span, Spanned, SpannedItem};
+use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 
+use std::convert::TryFrom;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
@@ -15,8 +20,16 @@ pub enum Unit {
     Gigabyte,
     Terabyte,
     Petabyte,
+    Kibibyte,
+    Mebibyte,
+    Gibibyte,
+    Tebibyte,
+    Pebibyte,
 
     // Duration units
+    Nanosecond,
+    Microsecond,
+    Millisecond,
     Second,
     Minute,
     Hour,
@@ -24,6 +37,16 @@ pub enum Unit {
     Week,
     Month,
     Year,
+    // Like `Year`, these count 365-day years, so they share its approximation
+    Decade,
+    Century,
+    Millennium,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    Filesize,
+    Duration,
 }
 
 impl PrettyDebug for Unit {
@@ -32,14 +55,66 @@ impl PrettyDebug for Unit {
     }
 }
 
-fn convert_number_to_u64(number: &Number) -> u64 {
-    match number {
-        Number::Int(big_int) => big_int.to_u64().unwrap(),
-        Number::Decimal(big_decimal) => big_decimal.to_u64().unwrap(),
+impl Unit {
+    /// Every unit, in declaration order. Keep this in sync with the enum.
+    pub fn all() -> &'static [Unit] {
+        &[
+            Unit::Byte,
+            Unit::Kilobyte,
+            Unit::Megabyte,
+            Unit::Gigabyte,
+            Unit::Terabyte,
+            Unit::Petabyte,
+            Unit::Kibibyte,
+            Unit::Mebibyte,
+            Unit::Gibibyte,
+            Unit::Tebibyte,
+            Unit::Pebibyte,
+            Unit::Nanosecond,
+            Unit::Microsecond,
+            Unit::Millisecond,
+            Unit::Second,
+            Unit::Minute,
+            Unit::Hour,
+            Unit::Day,
+            Unit::Week,
+            Unit::Month,
+            Unit::Year,
+            Unit::Decade,
+            Unit::Century,
+            Unit::Millennium,
+        ]
+    }
+
+    pub fn kind(&self) -> UnitKind {
+        match self {
+            Unit::Byte
+            | Unit::Kilobyte
+            | Unit::Megabyte
+            | Unit::Gigabyte
+            | Unit::Terabyte
+            | Unit::Petabyte
+            | Unit::Kibibyte
+            | Unit::Mebibyte
+            | Unit::Gibibyte
+            | Unit::Tebibyte
+            | Unit::Pebibyte => UnitKind::Filesize,
+            Unit::Nanosecond
+            | Unit::Microsecond
+            | Unit::Millisecond
+            | Unit::Second
+            | Unit::Minute
+            | Unit::Hour
+            | Unit::Day
+            | Unit::Week
+            | Unit::Month
+            | Unit::Year
+            | Unit::Decade
+            | Unit::Century
+            | Unit::Millennium => UnitKind::Duration,
+        }
     }
-}
 
-impl Unit {
     pub fn as_str(&self) -> &str {
         match *self {
             Unit::Byte => "B",
@@ -48,6 +123,14 @@ impl Unit {
             Unit::Gigabyte => "GB",
             Unit::Terabyte => "TB",
             Unit::Petabyte => "PB",
+            Unit::Kibibyte => "KiB",
+            Unit::Mebibyte => "MiB",
+            Unit::Gibibyte => "GiB",
+            Unit::Tebibyte => "TiB",
+            Unit::Pebibyte => "PiB",
+            Unit::Nanosecond => "ns",
+            Unit::Microsecond => "us",
+            Unit::Millisecond => "ms",
             Unit::Second => "s",
             Unit::Minute => "m",
             Unit::Hour => "h",
@@ -55,61 +138,725 @@ impl Unit {
             Unit::Week => "w",
             Unit::Month => "M",
             Unit::Year => "y",
+            Unit::Decade => "dec",
+            Unit::Century => "cen",
+            Unit::Millennium => "mil",
+        }
+    }
+
+    /// The size of one unit, in bytes for filesizes and in nanoseconds for durations.
+    pub fn base_units(&self) -> u128 {
+        const SECOND: u128 = 1_000_000_000;
+        const DAY: u128 = 24 * 60 * 60 * SECOND;
+        const YEAR: u128 = 365 * DAY;
+
+        match self {
+            Unit::Byte => 1,
+            Unit::Kilobyte => 1000,
+            Unit::Megabyte => 1000u128.pow(2),
+            Unit::Gigabyte => 1000u128.pow(3),
+            Unit::Terabyte => 1000u128.pow(4),
+            Unit::Petabyte => 1000u128.pow(5),
+            Unit::Kibibyte => 1024,
+            Unit::Mebibyte => 1024u128.pow(2),
+            Unit::Gibibyte => 1024u128.pow(3),
+            Unit::Tebibyte => 1024u128.pow(4),
+            Unit::Pebibyte => 1024u128.pow(5),
+            Unit::Nanosecond => 1,
+            Unit::Microsecond => 1000,
+            Unit::Millisecond => 1000 * 1000,
+            Unit::Second => SECOND,
+            Unit::Minute => 60 * SECOND,
+            Unit::Hour => 60 * 60 * SECOND,
+            Unit::Day => DAY,
+            Unit::Week => 7 * DAY,
+            Unit::Month => 30 * DAY,
+            Unit::Year => YEAR,
+            Unit::Decade => 10 * YEAR,
+            Unit::Century => 100 * YEAR,
+            Unit::Millennium => 1000 * YEAR,
+        }
+    }
+
+    pub fn compute(&self, size: Spanned<&Number>) -> Result<UntaggedValue, ShellError> {
+        let scaled = size.item.clone() * Number::from(self.base_units());
+
+        // Sizes and durations both stay in big-number space. Fractional sizes are rounded to
+        // the nearest whole byte, and fractional durations truncated to whole nanoseconds.
+        match self.kind() {
+            UnitKind::Filesize => Ok(bytes(scaled)),
+            UnitKind::Duration => Ok(duration(scaled)),
         }
     }
 
-    pub fn compute(&self, size: &Number) -> UntaggedValue {
-        let size = size.clone();
-
-        match &self {
-            Unit::Byte => number(size),
-            Unit::Kilobyte => number(size * 1024),
-            Unit::Megabyte => number(size * 1024 * 1024),
-            Unit::Gigabyte => number(size * 1024 * 1024 * 1024),
-            Unit::Terabyte => number(size * 1024 * 1024 * 1024 * 1024),
-            Unit::Petabyte => number(size * 1024 * 1024 * 1024 * 1024 * 1024),
-            Unit::Second => duration(convert_number_to_u64(&size)),
-            Unit::Minute => duration(60 * convert_number_to_u64(&size)),
-            Unit::Hour => duration(60 * 60 * convert_number_to_u64(&size)),
-            Unit::Day => duration(24 * 60 * 60 * convert_number_to_u64(&size)),
-            Unit::Week => duration(7 * 24 * 60 * 60 * convert_number_to_u64(&size)),
-            Unit::Month => duration(30 * 24 * 60 * 60 * convert_number_to_u64(&size)),
-            Unit::Year => duration(365 * 24 * 60 * 60 * convert_number_to_u64(&size)),
+    /// Re-expresses `value`, an amount of this unit, as an amount of `to`. The result stays
+    /// an integer when the amount divides evenly, and becomes a decimal otherwise.
+    pub fn convert(&self, value: &UntaggedValue, to: Unit) -> Result<UntaggedValue, ShellError> {
+        if self.kind() != to.kind() {
+            return Err(ShellError::untagged_runtime_error(format!(
+                "Cannot convert {} to {}: only units of the same kind convert",
+                self.as_str(),
+                to.as_str()
+            )));
+        }
+
+        let from_units = BigInt::from(self.base_units());
+        let to_units = BigInt::from(to.base_units());
+
+        let converted = match value {
+            UntaggedValue::Primitive(Primitive::Int(int)) => {
+                let scaled = int * from_units;
+
+                if (&scaled % &to_units).is_zero() {
+                    Primitive::Int(scaled / to_units)
+                } else {
+                    Primitive::Decimal(BigDecimal::from(scaled) / BigDecimal::from(to_units))
+                }
+            }
+            UntaggedValue::Primitive(Primitive::Decimal(decimal)) => Primitive::Decimal(
+                decimal * BigDecimal::from(from_units) / BigDecimal::from(to_units),
+            ),
+            other => {
+                return Err(ShellError::untagged_runtime_error(format!(
+                    "Cannot convert {} to {}: expected a number, found {}",
+                    self.as_str(),
+                    to.as_str(),
+                    other.type_name()
+                )))
+            }
+        };
+
+        Ok(UntaggedValue::Primitive(converted))
+    }
+
+    /// Like `compute`, but months and longer units count actual calendar months from `anchor`
+    /// instead of 30-day months and 365-day years. A month from January 31st ends on the last
+    /// day of February, and so does a year from February 29th. Shorter units have a fixed
+    /// length and are computed as usual.
+    pub fn compute_calendar(
+        &self,
+        size: Spanned<&Number>,
+        anchor: DateTime<Utc>,
+    ) -> Result<UntaggedValue, ShellError> {
+        let months_per_unit: u64 = match self {
+            Unit::Month => 1,
+            Unit::Year => 12,
+            Unit::Decade => 12 * 10,
+            Unit::Century => 12 * 100,
+            Unit::Millennium => 12 * 1000,
+            _ => return self.compute(size),
+        };
+
+        let span = size.span;
+
+        let count = match size.item {
+            Number::Int(_) => size.item.to_u64_checked(span)?,
+            Number::Decimal(_) => {
+                return Err(ShellError::labeled_error(
+                    "Expected a whole number",
+                    "calendar months and years can't be split",
+                    span,
+                ))
+            }
+        };
+
+        let end = count
+            .checked_mul(months_per_unit)
+            .and_then(|months| add_months(anchor, months))
+            .ok_or_else(|| {
+                ShellError::range_error(
+                    ExpectedRange::U64,
+                    &"bigger number".spanned(span),
+                    "adding calendar months to a date",
+                )
+            })?;
+
+        match (end - anchor).num_nanoseconds() {
+            Some(nanos) => Ok(duration(Number::from(nanos))),
+            None => Err(ShellError::range_error(
+                ExpectedRange::U64,
+                &"bigger number".spanned(span),
+                "converting to a duration in nanoseconds",
+            )),
+        }
+    }
+
+    /// Parses consecutive number and suffix pairs, such as `1h30m`, into the sum of the parts.
+    pub fn parse_compound(input: Spanned<&str>) -> Result<UntaggedValue, ShellError> {
+        let span = input.span;
+        let mut rest = input.item;
+        let mut total: Option<(UnitKind, UntaggedValue)> = None;
+
+        while !rest.is_empty() {
+            let number_len = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.')
+                .unwrap_or(rest.len());
+            let (amount, remaining) = rest.split_at(number_len);
+
+            let suffix_len = remaining
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(remaining.len());
+            let (suffix, remaining) = remaining.split_at(suffix_len);
+
+            rest = remaining;
+
+            let amount = parse_amount(amount).ok_or_else(|| {
+                ShellError::labeled_error("Expected a number", "before this unit", span)
+            })?;
+
+            let unit = Unit::from_str(suffix).map_err(|_| {
+                ShellError::labeled_error(
+                    "Expected a unit",
+                    format!("unknown unit {:?}", suffix),
+                    span,
+                )
+            })?;
+
+            let part = unit.compute((&amount).spanned(span))?;
+
+            total = Some(match total {
+                None => (unit.kind(), part),
+                Some((kind, _)) if kind != unit.kind() => {
+                    return Err(ShellError::labeled_error(
+                        "Cannot mix filesize and duration units",
+                        "in this literal",
+                        span,
+                    ))
+                }
+                Some((kind, total)) => (kind, add(total, part, span)?),
+            });
         }
+
+        total
+            .map(|(_, total)| total)
+            .ok_or_else(|| ShellError::labeled_error("Expected a unit", "empty literal", span))
+    }
+}
+
+/// Moves `date` forward by whole calendar months, clamping the day to the length of the
+/// month it lands in.
+fn add_months(date: DateTime<Utc>, months: u64) -> Option<DateTime<Utc>> {
+    let months = i64::try_from(months).ok()?;
+    let total = (i64::from(date.year()) * 12 + i64::from(date.month0())).checked_add(months)?;
+
+    let year = i32::try_from(total / 12).ok()?;
+    let month = (total % 12) as u32 + 1;
+    let day = date.day().min(days_in_month(year, month)?);
+
+    let naive = NaiveDate::from_ymd_opt(year, month, day)?.and_time(date.time());
+
+    Some(DateTime::from_utc(naive, Utc))
+}
+
+fn days_in_month(year: i32, month: u32) -> Option<u32> {
+    let first_of_next = if month == 12 {
+        NaiveDate::from_ymd_opt(year.checked_add(1)?, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+
+    Some(first_of_next.pred().day())
+}
+
+fn parse_amount(amount: &str) -> Option<Number> {
+    if amount.contains('.') {
+        BigDecimal::from_str(amount).ok().map(Number::Decimal)
+    } else {
+        BigInt::from_str(amount).ok().map(Number::Int)
     }
 }
 
-fn number(number: impl Into<Number>) -> UntaggedValue {
-    let number = number.into();
+fn add(left: UntaggedValue, right: UntaggedValue, span: Span) -> Result<UntaggedValue, ShellError> {
+    let sum = match (left, right) {
+        (
+            UntaggedValue::Primitive(Primitive::Duration(left)),
+            UntaggedValue::Primitive(Primitive::Duration(right)),
+        ) => Primitive::Duration(left + right),
+        (
+            UntaggedValue::Primitive(Primitive::Int(left)),
+            UntaggedValue::Primitive(Primitive::Int(right)),
+        ) => Primitive::Int(left + right),
+        (
+            UntaggedValue::Primitive(Primitive::Int(int)),
+            UntaggedValue::Primitive(Primitive::Decimal(decimal)),
+        )
+        | (
+            UntaggedValue::Primitive(Primitive::Decimal(decimal)),
+            UntaggedValue::Primitive(Primitive::Int(int)),
+        ) => Primitive::Decimal(BigDecimal::from(int) + decimal),
+        (
+            UntaggedValue::Primitive(Primitive::Decimal(left)),
+            UntaggedValue::Primitive(Primitive::Decimal(right)),
+        ) => Primitive::Decimal(left + right),
+        (left, right) => {
+            return Err(ShellError::labeled_error(
+                "Cannot mix filesize and duration units",
+                format!("can't add {} to {}", right.type_name(), left.type_name()),
+                span,
+            ))
+        }
+    };
+
+    Ok(UntaggedValue::Primitive(sum))
+}
 
-    match number {
+/// Rounds a fractional byte count half away from zero, so `0.5B` is one byte.
+fn bytes(size: Number) -> UntaggedValue {
+    match size {
         Number::Int(int) => UntaggedValue::Primitive(Primitive::Int(int)),
-        Number::Decimal(decimal) => UntaggedValue::Primitive(Primitive::Decimal(decimal)),
+        Number::Decimal(decimal) => {
+            let half = BigDecimal::new(BigInt::from(5), 1);
+            let rounded = if decimal.sign() == Sign::Minus {
+                decimal - half
+            } else {
+                decimal + half
+            };
+
+            let (int, _) = rounded.with_scale(0).as_bigint_and_exponent();
+            UntaggedValue::Primitive(Primitive::Int(int))
+        }
     }
 }
 
-pub fn duration(secs: u64) -> UntaggedValue {
-    UntaggedValue::Primitive(Primitive::Duration(secs))
+fn duration(nanos: Number) -> UntaggedValue {
+    let nanos = match nanos {
+        Number::Int(int) => int,
+        Number::Decimal(decimal) => decimal.with_scale(0).as_bigint_and_exponent().0,
+    };
+
+    UntaggedValue::Primitive(Primitive::Duration(nanos))
 }
 
+/// Duration suffixes are matched case-sensitively, since `m` (minute) and `M` (month) are
+/// different units. Size suffixes never collide with them, so they are case-insensitive.
 impl FromStr for Unit {
     type Err = ();
     fn from_str(input: &str) -> Result<Self, <Self as std::str::FromStr>::Err> {
         match input {
-            "B" | "b" => Ok(Unit::Byte),
-            "KB" | "kb" | "Kb" | "K" | "k" => Ok(Unit::Kilobyte),
-            "MB" | "mb" | "Mb" => Ok(Unit::Megabyte),
-            "GB" | "gb" | "Gb" => Ok(Unit::Gigabyte),
-            "TB" | "tb" | "Tb" => Ok(Unit::Terabyte),
-            "PB" | "pb" | "Pb" => Ok(Unit::Petabyte),
-            "s" => Ok(Unit::Second),
-            "m" => Ok(Unit::Minute),
-            "h" => Ok(Unit::Hour),
-            "d" => Ok(Unit::Day),
-            "w" => Ok(Unit::Week),
-            "M" => Ok(Unit::Month),
-            "y" => Ok(Unit::Year),
+            "ns" => return Ok(Unit::Nanosecond),
+            "us" | "µs" => return Ok(Unit::Microsecond),
+            "ms" => return Ok(Unit::Millisecond),
+            "s" => return Ok(Unit::Second),
+            "m" => return Ok(Unit::Minute),
+            "h" => return Ok(Unit::Hour),
+            "d" => return Ok(Unit::Day),
+            "w" => return Ok(Unit::Week),
+            "M" => return Ok(Unit::Month),
+            "y" => return Ok(Unit::Year),
+            "dec" => return Ok(Unit::Decade),
+            "cen" => return Ok(Unit::Century),
+            "mil" => return Ok(Unit::Millennium),
+            _ => {}
+        }
+
+        match &input.to_ascii_lowercase()[..] {
+            "b" => Ok(Unit::Byte),
+            "kb" | "k" => Ok(Unit::Kilobyte),
+            "mb" => Ok(Unit::Megabyte),
+            "gb" => Ok(Unit::Gigabyte),
+            "tb" => Ok(Unit::Terabyte),
+            "pb" => Ok(Unit::Petabyte),
+            "kib" => Ok(Unit::Kibibyte),
+            "mib" => Ok(Unit::Mebibyte),
+            "gib" => Ok(Unit::Gibibyte),
+            "tib" => Ok(Unit::Tebibyte),
+            "pib" => Ok(Unit::Pebibyte),
             _ => Err(()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Unit, UnitKind};
+    use crate::parse::parser::Number;
+    use bigdecimal::BigDecimal;
+    use chrono::{DateTime, TimeZone, Utc};
+    use nu_errors::ShellError;
+    use nu_protocol::UntaggedValue;
+    use nu_source::{Span, SpannedItem};
+    use num_bigint::BigInt;
+    use std::str::FromStr;
+
+    fn compute_with(unit: Unit, size: impl Into<Number>) -> UntaggedValue {
+        unit.compute((&size.into()).spanned_unknown()).unwrap()
+    }
+
+    fn compute(input: &str) -> UntaggedValue {
+        compute_with(Unit::from_str(input).unwrap(), 1)
+    }
+
+    #[test]
+    fn binary_units_are_powers_of_1024() {
+        assert_eq!(compute("KiB"), UntaggedValue::int(1024));
+        assert_eq!(compute("MiB"), UntaggedValue::int(1024 * 1024));
+    }
+
+    #[test]
+    fn decimal_units_are_powers_of_1000() {
+        assert_eq!(compute("KB"), UntaggedValue::int(1000));
+        assert_eq!(compute("MB"), UntaggedValue::int(1000 * 1000));
+    }
+
+    #[test]
+    fn one_second_is_a_thousand_milliseconds() {
+        assert_eq!(
+            compute_with(Unit::Second, 1),
+            compute_with(Unit::Millisecond, 1000)
+        );
+        assert_eq!(
+            compute_with(Unit::Millisecond, 1),
+            compute_with(Unit::Microsecond, 1000)
+        );
+        assert_eq!(
+            compute_with(Unit::Microsecond, 1),
+            compute_with(Unit::Nanosecond, 1000)
+        );
+    }
+
+    #[test]
+    fn large_sizes_do_not_overflow() {
+        let expected = Number::from(9000) * Number::from(1_000_000_000_000_000u64);
+
+        assert_eq!(compute_with(Unit::Petabyte, 9000), expected.into());
+    }
+
+    #[test]
+    fn durations_too_large_for_a_u64_of_nanoseconds_are_kept_whole() {
+        let nanos = BigInt::from(1_000_000) * BigInt::from(Unit::Year.base_units());
+
+        assert_eq!(
+            compute_with(Unit::Year, 1_000_000),
+            UntaggedValue::duration(nanos)
+        );
+    }
+
+    #[test]
+    fn accepted_spellings() {
+        let cases = vec![
+            ("B", Ok(Unit::Byte)),
+            ("b", Ok(Unit::Byte)),
+            ("KB", Ok(Unit::Kilobyte)),
+            ("kb", Ok(Unit::Kilobyte)),
+            ("Kb", Ok(Unit::Kilobyte)),
+            ("kB", Ok(Unit::Kilobyte)),
+            ("K", Ok(Unit::Kilobyte)),
+            ("k", Ok(Unit::Kilobyte)),
+            ("MB", Ok(Unit::Megabyte)),
+            ("mb", Ok(Unit::Megabyte)),
+            ("Mb", Ok(Unit::Megabyte)),
+            ("GB", Ok(Unit::Gigabyte)),
+            ("gb", Ok(Unit::Gigabyte)),
+            ("TB", Ok(Unit::Terabyte)),
+            ("tb", Ok(Unit::Terabyte)),
+            ("PB", Ok(Unit::Petabyte)),
+            ("pb", Ok(Unit::Petabyte)),
+            ("KiB", Ok(Unit::Kibibyte)),
+            ("kib", Ok(Unit::Kibibyte)),
+            ("KIB", Ok(Unit::Kibibyte)),
+            ("MiB", Ok(Unit::Mebibyte)),
+            ("mib", Ok(Unit::Mebibyte)),
+            ("GiB", Ok(Unit::Gibibyte)),
+            ("TiB", Ok(Unit::Tebibyte)),
+            ("PiB", Ok(Unit::Pebibyte)),
+            ("ns", Ok(Unit::Nanosecond)),
+            ("us", Ok(Unit::Microsecond)),
+            ("µs", Ok(Unit::Microsecond)),
+            ("ms", Ok(Unit::Millisecond)),
+            ("s", Ok(Unit::Second)),
+            ("m", Ok(Unit::Minute)),
+            ("h", Ok(Unit::Hour)),
+            ("d", Ok(Unit::Day)),
+            ("w", Ok(Unit::Week)),
+            ("M", Ok(Unit::Month)),
+            ("y", Ok(Unit::Year)),
+            ("dec", Ok(Unit::Decade)),
+            ("cen", Ok(Unit::Century)),
+            ("mil", Ok(Unit::Millennium)),
+            ("DEC", Err(())),
+            ("NS", Err(())),
+            ("Ms", Err(())),
+            ("MS", Err(())),
+            ("S", Err(())),
+            ("H", Err(())),
+            ("D", Err(())),
+            ("W", Err(())),
+            ("Y", Err(())),
+            ("", Err(())),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(Unit::from_str(input), expected, "parsing {:?}", input);
+        }
+    }
+
+    fn compound(input: &str) -> Result<UntaggedValue, ShellError> {
+        Unit::parse_compound(input.spanned_unknown())
+    }
+
+    #[test]
+    fn compound_durations_are_summed() {
+        assert_eq!(compound("1h30m").unwrap(), compute_with(Unit::Minute, 90));
+        assert_eq!(compound("2d4h").unwrap(), compute_with(Unit::Hour, 52));
+        assert_eq!(compound("1m1h").unwrap(), compute_with(Unit::Minute, 61));
+        assert_eq!(
+            compound("1GB512MB").unwrap(),
+            compute_with(Unit::Megabyte, 1512)
+        );
+    }
+
+    #[test]
+    fn long_spans_of_years_are_multiples_of_the_year() {
+        assert_eq!(compute_with(Unit::Decade, 1), compute_with(Unit::Year, 10));
+        assert_eq!(
+            compute_with(Unit::Century, 1),
+            compute_with(Unit::Year, 100)
+        );
+        assert_eq!(
+            compute_with(Unit::Millennium, decimal("0.5")),
+            compute_with(Unit::Year, 500)
+        );
+        assert_eq!(compound("1dec5y").unwrap(), compute_with(Unit::Year, 15));
+    }
+
+    #[test]
+    fn a_full_millennium_is_a_thousand_years() {
+        assert_eq!(
+            compute_with(Unit::Millennium, 1),
+            compute_with(Unit::Year, 1000)
+        );
+    }
+
+    #[test]
+    fn adding_parts_of_different_kinds_fails() {
+        let sum = super::add(
+            compute_with(Unit::Hour, 1),
+            compute_with(Unit::Megabyte, 1),
+            Span::unknown(),
+        );
+
+        assert!(sum.is_err());
+    }
+
+    #[test]
+    fn compound_literals_reject_invalid_parts() {
+        assert!(compound("1h1MB").is_err());
+        assert!(compound("1h30").is_err());
+        assert!(compound("h30m").is_err());
+        assert!(compound("").is_err());
+    }
+
+    #[test]
+    fn every_unit_has_the_expected_kind() {
+        let cases = vec![
+            (Unit::Byte, UnitKind::Filesize),
+            (Unit::Kilobyte, UnitKind::Filesize),
+            (Unit::Megabyte, UnitKind::Filesize),
+            (Unit::Gigabyte, UnitKind::Filesize),
+            (Unit::Terabyte, UnitKind::Filesize),
+            (Unit::Petabyte, UnitKind::Filesize),
+            (Unit::Kibibyte, UnitKind::Filesize),
+            (Unit::Mebibyte, UnitKind::Filesize),
+            (Unit::Gibibyte, UnitKind::Filesize),
+            (Unit::Tebibyte, UnitKind::Filesize),
+            (Unit::Pebibyte, UnitKind::Filesize),
+            (Unit::Nanosecond, UnitKind::Duration),
+            (Unit::Microsecond, UnitKind::Duration),
+            (Unit::Millisecond, UnitKind::Duration),
+            (Unit::Second, UnitKind::Duration),
+            (Unit::Minute, UnitKind::Duration),
+            (Unit::Hour, UnitKind::Duration),
+            (Unit::Day, UnitKind::Duration),
+            (Unit::Week, UnitKind::Duration),
+            (Unit::Month, UnitKind::Duration),
+            (Unit::Year, UnitKind::Duration),
+            (Unit::Decade, UnitKind::Duration),
+            (Unit::Century, UnitKind::Duration),
+            (Unit::Millennium, UnitKind::Duration),
+        ];
+
+        for (unit, kind) in cases {
+            assert_eq!(unit.kind(), kind, "kind of {:?}", unit);
+        }
+    }
+
+    #[test]
+    fn every_unit_round_trips_through_as_str() {
+        for unit in Unit::all() {
+            assert_eq!(
+                Unit::from_str(unit.as_str()),
+                Ok(*unit),
+                "parsing {:?}",
+                unit.as_str()
+            );
+        }
+    }
+
+    #[test]
+    fn all_lists_every_unit_once() {
+        let units = Unit::all();
+        let distinct: std::collections::HashSet<_> = units.iter().collect();
+
+        assert_eq!(units.len(), 24);
+        assert_eq!(distinct.len(), units.len());
+        assert!(units.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    fn decimal(input: &str) -> Number {
+        Number::Decimal(BigDecimal::from_str(input).unwrap())
+    }
+
+    #[test]
+    fn fractional_sizes_round_to_whole_bytes() {
+        assert_eq!(
+            compute_with(Unit::Kilobyte, decimal("1.5")),
+            UntaggedValue::int(1500)
+        );
+        assert_eq!(
+            compute_with(Unit::Kibibyte, decimal("1.5")),
+            UntaggedValue::int(1536)
+        );
+        assert_eq!(
+            compute_with(Unit::Megabyte, decimal("0.5")),
+            UntaggedValue::int(500_000)
+        );
+        assert_eq!(
+            compute_with(Unit::Kilobyte, decimal("0.0005")),
+            UntaggedValue::int(1)
+        );
+        assert_eq!(
+            compute_with(Unit::Kilobyte, decimal("0.0004")),
+            UntaggedValue::int(0)
+        );
+        assert_eq!(
+            compute_with(Unit::Byte, decimal("-2.5")),
+            UntaggedValue::int(-3)
+        );
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.ymd(year, month, day).and_hms(12, 0, 0)
+    }
+
+    fn calendar(unit: Unit, size: impl Into<Number>, anchor: DateTime<Utc>) -> UntaggedValue {
+        unit.compute_calendar((&size.into()).spanned_unknown(), anchor)
+            .unwrap()
+    }
+
+    #[test]
+    fn calendar_months_have_their_actual_length() {
+        assert_eq!(
+            calendar(Unit::Month, 1, date(2019, 1, 1)),
+            compute_with(Unit::Day, 31)
+        );
+        assert_eq!(
+            calendar(Unit::Month, 1, date(2019, 2, 1)),
+            compute_with(Unit::Day, 28)
+        );
+        assert_eq!(
+            calendar(Unit::Month, 2, date(2019, 12, 1)),
+            compute_with(Unit::Day, 31 + 31)
+        );
+    }
+
+    #[test]
+    fn calendar_months_clamp_to_the_end_of_shorter_months() {
+        assert_eq!(
+            calendar(Unit::Month, 1, date(2020, 1, 31)),
+            compute_with(Unit::Day, 29)
+        );
+        assert_eq!(
+            calendar(Unit::Month, 1, date(2019, 1, 31)),
+            compute_with(Unit::Day, 28)
+        );
+    }
+
+    #[test]
+    fn calendar_years_count_leap_days() {
+        assert_eq!(
+            calendar(Unit::Year, 1, date(2020, 1, 1)),
+            compute_with(Unit::Day, 366)
+        );
+        assert_eq!(
+            calendar(Unit::Year, 1, date(2020, 2, 29)),
+            compute_with(Unit::Day, 365)
+        );
+        assert_eq!(
+            calendar(Unit::Year, 4, date(2020, 2, 29)),
+            compute_with(Unit::Day, 4 * 365 + 1)
+        );
+        assert_eq!(
+            calendar(Unit::Decade, 1, date(2020, 1, 1)),
+            compute_with(Unit::Day, 10 * 365 + 3)
+        );
+    }
+
+    #[test]
+    fn calendar_computation_keeps_fixed_units_as_they_are() {
+        assert_eq!(
+            calendar(Unit::Week, 2, date(2020, 2, 29)),
+            compute_with(Unit::Week, 2)
+        );
+        assert_eq!(
+            calendar(Unit::Megabyte, 2, date(2020, 2, 29)),
+            compute_with(Unit::Megabyte, 2)
+        );
+    }
+
+    #[test]
+    fn calendar_months_must_be_whole() {
+        let size = decimal("1.5");
+
+        assert!(Unit::Month
+            .compute_calendar((&size).spanned_unknown(), date(2020, 1, 1))
+            .is_err());
+    }
+
+    #[test]
+    fn converts_between_units_of_the_same_kind() {
+        assert_eq!(
+            Unit::Second
+                .convert(&UntaggedValue::int(3600), Unit::Hour)
+                .unwrap(),
+            UntaggedValue::int(1)
+        );
+        assert_eq!(
+            Unit::Kibibyte
+                .convert(&UntaggedValue::int(2), Unit::Byte)
+                .unwrap(),
+            UntaggedValue::int(2048)
+        );
+        assert_eq!(
+            Unit::Minute
+                .convert(&UntaggedValue::int(90), Unit::Hour)
+                .unwrap(),
+            UntaggedValue::decimal(BigDecimal::from_str("1.5").unwrap())
+        );
+        assert_eq!(
+            Unit::Hour
+                .convert(
+                    &UntaggedValue::decimal(BigDecimal::from_str("0.5").unwrap()),
+                    Unit::Minute
+                )
+                .unwrap(),
+            UntaggedValue::decimal(BigDecimal::from(30))
+        );
+    }
+
+    #[test]
+    fn conversion_rejects_units_of_another_kind() {
+        assert!(Unit::Second
+            .convert(&UntaggedValue::int(1), Unit::Byte)
+            .is_err());
+        assert!(Unit::Byte
+            .convert(&UntaggedValue::int(1), Unit::Second)
+            .is_err());
+    }
+
+    #[test]
+    fn conversion_rejects_values_that_are_not_numbers() {
+        assert!(Unit::Second
+            .convert(&UntaggedValue::string("3600"), Unit::Hour)
+            .is_err());
+    }
+}
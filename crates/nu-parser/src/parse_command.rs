@@ -2,13 +2,13 @@ use crate::hir::syntax_shape::{
     color_fallible_syntax, color_syntax, expand_expr, flat_shape::FlatShape, spaced,
     BackoffColoringMode, ColorSyntax, MaybeSpaceShape,
 };
-use crate::TokensIterator;
 use crate::{
     hir::{self, ExpandContext, NamedArguments},
     Flag,
 };
+use crate::{TokenNode, TokensIterator};
 use log::trace;
-use nu_source::{PrettyDebugWithSource, Span, Spanned, SpannedItem, Text};
+use nu_source::{HasSpan, PrettyDebugWithSource, Span, Spanned, SpannedItem, Text};
 
 use nu_errors::{ArgumentError, ParseError};
 use nu_protocol::{NamedType, PositionalType, Signature};
@@ -20,6 +20,7 @@ pub fn parse_command_tail(
     command_span: Span,
 ) -> Result<Option<(Option<Vec<hir::Expression>>, Option<NamedArguments>)>, ParseError> {
     let mut named = NamedArguments::new();
+    let mut switches = vec![];
     trace_remaining("nodes", &tail, context.source());
 
     for (name, kind) in &config.named {
@@ -27,9 +28,13 @@ pub fn parse_command_tail(
 
         match &kind.0 {
             NamedType::Switch => {
-                let flag = extract_switch(name, tail, context.source());
+                let flag = extract_switch(config, name, tail, context.source());
 
-                named.insert_switch(name, flag);
+                if let Some((pos, _)) = flag {
+                    switches.push((name, pos));
+                }
+
+                named.insert_switch(name, flag.map(|(_, flag)| flag));
             }
             NamedType::Mandatory(syntax_type) => {
                 match extract_mandatory(config, name, tail, context.source(), command_span) {
@@ -52,7 +57,7 @@ pub fn parse_command_tail(
                 }
             }
             NamedType::Optional(syntax_type) => {
-                match extract_optional(name, tail, context.source()) {
+                match extract_optional(config, name, tail, context.source()) {
                     Err(err) => return Err(err), // produce a correct diagnostic
                     Ok(Some((pos, flag))) => {
                         tail.move_to(pos);
@@ -83,6 +88,31 @@ pub fn parse_command_tail(
         };
     }
 
+    if let Some(flag) = extract_unknown_flag(config, tail, context.source()) {
+        return Err(ParseError::argument_error(
+            config.name.clone().spanned(flag.span),
+            ArgumentError::UnknownFlag(
+                flag.span.slice(context.source()).to_string(),
+                config.named.keys().cloned().collect(),
+            ),
+        ));
+    }
+
+    // Every flag and flag value has been consumed by now, so a switch with arguments on both
+    // sides of it was given one of them as a value
+    for (name, pos) in switches {
+        if tail.unseen_before(pos).is_none() {
+            continue;
+        }
+
+        if let Some(value) = tail.unseen_after(pos) {
+            return Err(ParseError::argument_error(
+                config.name.clone().spanned(value.span()),
+                ArgumentError::UnexpectedValueForSwitch(name.to_string()),
+            ));
+        }
+    }
+
     trace_remaining("after named", &tail, context.source());
 
     let mut positional = vec![];
@@ -243,7 +273,10 @@ impl ColorSyntax for CommandTailShape {
 
             match &kind.0 {
                 NamedType::Switch => {
-                    match token_nodes.extract(|t| t.as_flag(name, context.source())) {
+                    let short = signature.short_for(name);
+
+                    match token_nodes.extract(|t| t.as_flag_or_short(name, short, context.source()))
+                    {
                         Some((pos, flag)) => args.insert(pos, vec![flag.color()]),
                         None => {}
                     }
@@ -265,7 +298,7 @@ impl ColorSyntax for CommandTailShape {
                     }
                 }
                 NamedType::Optional(syntax_type) => {
-                    match extract_optional(name, token_nodes, context.source()) {
+                    match extract_optional(signature, name, token_nodes, context.source()) {
                         Err(_) => {
                             // The optional flag didn't exist at all, so there's nothing to color
                         }
@@ -366,8 +399,17 @@ impl ColorSyntax for CommandTailShape {
     }
 }
 
-fn extract_switch(name: &str, tokens: &mut hir::TokensIterator<'_>, source: &Text) -> Option<Flag> {
-    tokens.extract(|t| t.as_flag(name, source)).map(|f| f.1)
+fn extract_switch(
+    config: &Signature,
+    name: &str,
+    tokens: &mut hir::TokensIterator<'_>,
+    source: &Text,
+) -> Option<(usize, Flag)> {
+    let short = config.short_for(name);
+    let (pos, flag) = tokens.extract(|t| t.as_flag_or_short(name, short, source))?;
+    tokens.remove_whitespace_before(pos);
+
+    Some((pos, flag))
 }
 
 fn extract_mandatory(
@@ -377,7 +419,8 @@ fn extract_mandatory(
     source: &Text,
     span: Span,
 ) -> Result<(usize, Flag), ParseError> {
-    let flag = tokens.extract(|t| t.as_flag(name, source));
+    let short = config.short_for(name);
+    let flag = tokens.extract(|t| t.as_flag_or_short(name, short, source));
 
     match flag {
         None => Err(ParseError::argument_error(
@@ -387,27 +430,53 @@ fn extract_mandatory(
 
         Some((pos, flag)) => {
             tokens.remove(pos);
+            tokens.remove_whitespace_before(pos);
             Ok((pos, flag))
         }
     }
 }
 
 fn extract_optional(
+    config: &Signature,
     name: &str,
     tokens: &mut hir::TokensIterator<'_>,
     source: &Text,
 ) -> Result<Option<(usize, Flag)>, ParseError> {
-    let flag = tokens.extract(|t| t.as_flag(name, source));
+    let short = config.short_for(name);
+    let flag = tokens.extract(|t| t.as_flag_or_short(name, short, source));
 
     match flag {
         None => Ok(None),
         Some((pos, flag)) => {
             tokens.remove(pos);
+            tokens.remove_whitespace_before(pos);
             Ok(Some((pos, flag)))
         }
     }
 }
 
+/// Finds a flag left over once every declared flag has been extracted, which means the
+/// command doesn't know about it
+fn extract_unknown_flag(
+    config: &Signature,
+    tokens: &mut hir::TokensIterator<'_>,
+    source: &Text,
+) -> Option<Flag> {
+    let (_, flag) = tokens.extract(|t| match t {
+        TokenNode::Flag(flag)
+            if !config.named.keys().any(|name| {
+                t.as_flag_or_short(name, config.short_for(name), source)
+                    .is_some()
+            }) =>
+        {
+            Some(*flag)
+        }
+        _ => None,
+    })?;
+
+    Some(flag)
+}
+
 pub fn trace_remaining(desc: &'static str, tail: &hir::TokensIterator<'_>, source: &Text) {
     trace!(
         target: "nu::parse",
@@ -2,7 +2,7 @@ use crate::value::Value;
 use derive_new::new;
 use indexmap::IndexMap;
 use nu_errors::ShellError;
-use nu_source::Tag;
+use nu_source::{span_for_spanned_list, Tag};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -18,12 +18,16 @@ pub struct EvaluatedArgs {
 }
 
 impl EvaluatedArgs {
+    /// The positionals starting at `from`, or an empty list if there are not that many
     pub fn slice_from(&self, from: usize) -> Vec<Value> {
         let positional = &self.positional;
 
         match positional {
             None => vec![],
-            Some(list) => list[from..].to_vec(),
+            Some(list) => list
+                .get(from..)
+                .map(|rest| rest.to_vec())
+                .unwrap_or_default(),
         }
     }
 
@@ -35,12 +39,33 @@ impl EvaluatedArgs {
     }
 
     pub fn expect_nth(&self, pos: usize) -> Result<&Value, ShellError> {
-        match &self.positional {
-            None => Err(ShellError::unimplemented("Better error: expect_nth")),
-            Some(array) => match array.iter().nth(pos) {
-                None => Err(ShellError::unimplemented("Better error: expect_nth")),
-                Some(item) => Ok(item),
-            },
+        let positional = match &self.positional {
+            None => &[][..],
+            Some(array) => &array[..],
+        };
+
+        if let Some(item) = positional.get(pos) {
+            return Ok(item);
+        }
+
+        let message = format!("Expected a positional argument at index {}", pos);
+
+        if positional.is_empty() {
+            Err(ShellError::untagged_runtime_error(format!(
+                "{}, but no positional arguments were supplied",
+                message
+            )))
+        } else {
+            let supplied = match positional.len() {
+                1 => "only 1 positional argument was supplied".to_string(),
+                n => format!("only {} positional arguments were supplied", n),
+            };
+
+            Err(ShellError::labeled_error(
+                message,
+                supplied,
+                span_for_spanned_list(positional.iter().map(|item| item.tag.span)),
+            ))
         }
     }
 
@@ -65,6 +90,12 @@ impl EvaluatedArgs {
         }
     }
 
+    pub fn expect_named(&self, name: &str) -> Result<&Value, ShellError> {
+        self.get(name).ok_or_else(|| {
+            ShellError::untagged_runtime_error(format!("Missing required flag --{}", name))
+        })
+    }
+
     pub fn positional_iter(&self) -> PositionalIter<'_> {
         match &self.positional {
             None => PositionalIter::Empty,
@@ -74,6 +105,13 @@ impl EvaluatedArgs {
             }
         }
     }
+
+    pub fn named_iter(&self) -> NamedIter<'_> {
+        match &self.named {
+            None => NamedIter::Empty,
+            Some(named) => NamedIter::Map(named.iter()),
+        }
+    }
 }
 
 pub enum PositionalIter<'a> {
@@ -91,3 +129,131 @@ impl<'a> Iterator for PositionalIter<'a> {
         }
     }
 }
+
+pub enum NamedIter<'a> {
+    Empty,
+    Map(indexmap::map::Iter<'a, String, Value>),
+}
+
+impl<'a> Iterator for NamedIter<'a> {
+    type Item = (&'a String, &'a Value);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            NamedIter::Empty => None,
+            NamedIter::Map(iter) => iter.next(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EvaluatedArgs;
+    use crate::value::{UntaggedValue, Value};
+    use indexmap::IndexMap;
+    use nu_source::Span;
+
+    fn int(i: i64, start: usize) -> Value {
+        UntaggedValue::int(i).into_value(Span::new(start, start + 1))
+    }
+
+    #[test]
+    fn expect_nth_errors_with_the_requested_index() {
+        let args = EvaluatedArgs::new(Some(vec![int(1, 0), int(2, 2)]), None);
+
+        let diagnostic = args.expect_nth(3).unwrap_err().to_diagnostic();
+
+        assert!(diagnostic.message.contains("index 3"));
+        assert_eq!(
+            diagnostic.labels[0].message,
+            Some("only 2 positional arguments were supplied".to_string())
+        );
+        assert_eq!(diagnostic.labels[0].span, Span::new(0, 3));
+    }
+
+    #[test]
+    fn expect_nth_errors_without_any_positionals() {
+        let args = EvaluatedArgs::new(None, None);
+
+        let diagnostic = args.expect_nth(0).unwrap_err().to_diagnostic();
+
+        assert!(diagnostic.message.contains("index 0"));
+        assert!(diagnostic.message.contains("no positional arguments"));
+    }
+
+    #[test]
+    fn expect_nth_gets_a_supplied_positional() {
+        let args = EvaluatedArgs::new(Some(vec![int(1, 0), int(2, 2)]), None);
+
+        assert_eq!(args.expect_nth(1).unwrap(), &int(2, 2));
+    }
+
+    #[test]
+    fn slice_from_gets_the_rest_of_the_positionals() {
+        let args = EvaluatedArgs::new(Some(vec![int(1, 0), int(2, 2), int(3, 4)]), None);
+
+        assert_eq!(args.slice_from(1), vec![int(2, 2), int(3, 4)]);
+    }
+
+    #[test]
+    fn slice_from_the_length_is_empty() {
+        let args = EvaluatedArgs::new(Some(vec![int(1, 0), int(2, 2)]), None);
+
+        assert!(args.slice_from(2).is_empty());
+    }
+
+    #[test]
+    fn slice_from_past_the_length_is_empty() {
+        let args = EvaluatedArgs::new(Some(vec![int(1, 0), int(2, 2)]), None);
+
+        assert!(args.slice_from(5).is_empty());
+        assert!(EvaluatedArgs::new(None, None).slice_from(5).is_empty());
+    }
+
+    #[test]
+    fn expect_named_gets_a_supplied_flag() {
+        let mut named = IndexMap::new();
+        named.insert("separator".to_string(), int(1, 0));
+
+        let args = EvaluatedArgs::new(None, Some(named));
+
+        assert_eq!(args.expect_named("separator").unwrap(), &int(1, 0));
+    }
+
+    #[test]
+    fn expect_named_errors_naming_the_missing_flag() {
+        let mut named = IndexMap::new();
+        named.insert("separator".to_string(), int(1, 0));
+
+        let args = EvaluatedArgs::new(None, Some(named));
+
+        let diagnostic = args.expect_named("lines").unwrap_err().to_diagnostic();
+        assert!(diagnostic.message.contains("Missing required flag --lines"));
+
+        let diagnostic = EvaluatedArgs::new(None, None)
+            .expect_named("lines")
+            .unwrap_err()
+            .to_diagnostic();
+        assert!(diagnostic.message.contains("Missing required flag --lines"));
+    }
+
+    #[test]
+    fn named_iter_follows_insertion_order() {
+        let mut named = IndexMap::new();
+        named.insert("zebra".to_string(), int(1, 0));
+        named.insert("apple".to_string(), int(2, 2));
+        named.insert("mango".to_string(), int(3, 4));
+
+        let args = EvaluatedArgs::new(None, Some(named));
+        let names: Vec<&str> = args.named_iter().map(|(name, _)| &name[..]).collect();
+
+        assert_eq!(names, vec!["zebra", "apple", "mango"]);
+    }
+
+    #[test]
+    fn named_iter_is_empty_without_named_args() {
+        let args = EvaluatedArgs::new(None, None);
+
+        assert_eq!(args.named_iter().count(), 0);
+    }
+}
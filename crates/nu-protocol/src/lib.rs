@@ -14,7 +14,7 @@ mod value;
 pub use crate::call_info::{CallInfo, EvaluatedArgs};
 pub use crate::maybe_owned::MaybeOwned;
 pub use crate::plugin::{serve_plugin, Plugin};
-pub use crate::return_value::{CommandAction, ReturnSuccess, ReturnValue};
+pub use crate::return_value::{CommandAction, LeaveShellScope, ReturnSuccess, ReturnValue};
 pub use crate::signature::{NamedType, PositionalType, Signature};
 pub use crate::syntax_shape::SyntaxShape;
 pub use crate::type_name::{PrettyType, ShellTypeName, SpannedTypeName};
@@ -22,7 +22,9 @@ pub use crate::type_shape::{Row as RowType, Type};
 pub use crate::value::column_path::{did_you_mean, ColumnPath, PathMember, UnspannedPathMember};
 pub use crate::value::dict::{Dictionary, TaggedDictBuilder};
 pub use crate::value::evaluate::{Evaluate, EvaluateTrait, Scope};
+pub use crate::value::primitive::format_compact_duration;
 pub use crate::value::primitive::format_primitive;
 pub use crate::value::primitive::Primitive;
+pub use crate::value::primitive::NANOS_PER_SEC;
 pub use crate::value::range::{Range, RangeInclusion};
 pub use crate::value::{UntaggedValue, Value};
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 pub enum CommandAction {
     ChangePath(String),
     Exit,
+    ExitWithCode(i32),
     Error(ShellError),
     EnterShell(String),
     AutoConvert(Value, String),
@@ -14,7 +15,16 @@ pub enum CommandAction {
     EnterHelpShell(Value),
     PreviousShell,
     NextShell,
-    LeaveShell,
+    LeaveShell(LeaveShellScope),
+}
+
+/// Which shells a `LeaveShell` action closes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LeaveShellScope {
+    /// Only the current shell; the shell manager moves on to the next one left.
+    Current,
+    /// Every open shell, leaving nu altogether.
+    All,
 }
 
 impl PrettyDebug for CommandAction {
@@ -22,6 +32,7 @@ impl PrettyDebug for CommandAction {
         match self {
             CommandAction::ChangePath(path) => b::typed("change path", b::description(path)),
             CommandAction::Exit => b::description("exit"),
+            CommandAction::ExitWithCode(code) => b::typed("exit", b::description(code)),
             CommandAction::Error(_) => b::error("error"),
             CommandAction::AutoConvert(_, extension) => {
                 b::typed("auto convert", b::description(extension))
@@ -31,7 +42,8 @@ impl PrettyDebug for CommandAction {
             CommandAction::EnterHelpShell(v) => b::typed("enter help shell", v.pretty()),
             CommandAction::PreviousShell => b::description("previous shell"),
             CommandAction::NextShell => b::description("next shell"),
-            CommandAction::LeaveShell => b::description("leave shell"),
+            CommandAction::LeaveShell(LeaveShellScope::Current) => b::description("leave shell"),
+            CommandAction::LeaveShell(LeaveShellScope::All) => b::description("leave all shells"),
         }
     }
 }
@@ -78,3 +90,23 @@ impl ReturnSuccess {
         Ok(ReturnSuccess::Action(input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CommandAction, ReturnSuccess, ReturnValue};
+
+    #[test]
+    fn action_constructor_matches_the_manual_form() {
+        let constructed = ReturnSuccess::action(CommandAction::ChangePath("/home".to_string()));
+        let manual: ReturnValue = Ok(ReturnSuccess::Action(CommandAction::ChangePath(
+            "/home".to_string(),
+        )));
+
+        assert_eq!(format!("{:?}", constructed), format!("{:?}", manual));
+
+        match constructed {
+            Ok(ReturnSuccess::Action(CommandAction::ChangePath(path))) => assert_eq!(path, "/home"),
+            other => panic!("expected a change path action, got {:?}", other),
+        }
+    }
+}
@@ -66,6 +66,17 @@ impl PositionalType {
             PositionalType::Optional(_, t) => t,
         }
     }
+
+    pub fn is_mandatory(&self) -> bool {
+        match self {
+            PositionalType::Mandatory(..) => true,
+            PositionalType::Optional(..) => false,
+        }
+    }
+
+    pub fn is_optional(&self) -> bool {
+        !self.is_mandatory()
+    }
 }
 
 type Description = String;
@@ -77,6 +88,9 @@ pub struct Signature {
     pub positional: Vec<(PositionalType, Description)>,
     pub rest_positional: Option<(SyntaxShape, Description)>,
     pub named: IndexMap<String, (NamedType, Description)>,
+    /// Single character aliases for named flags, from the alias to the flag's full name
+    #[serde(default)]
+    pub short_named: IndexMap<char, String>,
     pub yields: Option<Type>,
     pub input: Option<Type>,
     pub is_filter: bool,
@@ -108,6 +122,7 @@ impl Signature {
             positional: vec![],
             rest_positional: None,
             named: IndexMap::new(),
+            short_named: IndexMap::new(),
             is_filter: false,
             yields: None,
             input: None,
@@ -182,6 +197,42 @@ impl Signature {
         self
     }
 
+    pub fn named_short(
+        self,
+        name: impl Into<String>,
+        ty: impl Into<SyntaxShape>,
+        desc: impl Into<String>,
+        short: char,
+    ) -> Signature {
+        let name = name.into();
+
+        self.named(name.clone(), ty, desc).short(short, name)
+    }
+
+    pub fn switch_short(
+        self,
+        name: impl Into<String>,
+        desc: impl Into<String>,
+        short: char,
+    ) -> Signature {
+        let name = name.into();
+
+        self.switch(name.clone(), desc).short(short, name)
+    }
+
+    fn short(mut self, short: char, name: String) -> Signature {
+        self.short_named.insert(short, name);
+        self
+    }
+
+    /// The single character alias of the named flag, if it has one
+    pub fn short_for(&self, name: &str) -> Option<char> {
+        self.short_named
+            .iter()
+            .find(|(_, long)| *long == name)
+            .map(|(short, _)| *short)
+    }
+
     pub fn filter(mut self) -> Signature {
         self.is_filter = true;
         self
@@ -201,4 +252,69 @@ impl Signature {
         self.input = Some(ty);
         self
     }
+
+    /// The number of positional arguments a call must supply
+    pub fn mandatory_positional_count(&self) -> usize {
+        self.positional
+            .iter()
+            .filter(|(positional, _)| positional.is_mandatory())
+            .count()
+    }
+
+    /// The most positional arguments a call can supply, or `None` if it takes any number
+    pub fn max_positional_count(&self) -> Option<usize> {
+        match self.rest_positional {
+            Some(_) => None,
+            None => Some(self.positional.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Signature;
+    use crate::syntax_shape::SyntaxShape;
+
+    fn signature() -> Signature {
+        Signature::build("nth")
+            .required("row number", SyntaxShape::Int, "the number of the row")
+            .optional("column", SyntaxShape::String, "the column to return")
+    }
+
+    #[test]
+    fn classifies_positionals() {
+        let signature = signature();
+
+        assert!(signature.positional[0].0.is_mandatory());
+        assert!(!signature.positional[0].0.is_optional());
+        assert!(signature.positional[1].0.is_optional());
+        assert!(!signature.positional[1].0.is_mandatory());
+    }
+
+    #[test]
+    fn counts_positionals() {
+        let signature = signature();
+
+        assert_eq!(signature.mandatory_positional_count(), 1);
+        assert_eq!(signature.max_positional_count(), Some(2));
+
+        let signature = signature.rest(SyntaxShape::Int, "more row numbers");
+
+        assert_eq!(signature.mandatory_positional_count(), 1);
+        assert_eq!(signature.max_positional_count(), None);
+    }
+
+    #[test]
+    fn registers_short_flag_aliases() {
+        let signature = Signature::build("exit")
+            .switch_short("now", "exit out of the shell immediately", 'n')
+            .named_short("code", SyntaxShape::Int, "the exit code", 'c')
+            .switch("verbose", "say more");
+
+        assert!(signature.named.contains_key("now"));
+        assert!(signature.named.contains_key("code"));
+        assert_eq!(signature.short_for("now"), Some('n'));
+        assert_eq!(signature.short_for("code"), Some('c'));
+        assert_eq!(signature.short_for("verbose"), None);
+    }
 }
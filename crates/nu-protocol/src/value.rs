@@ -97,6 +97,18 @@ impl UntaggedValue {
         }
     }
 
+    /// Nothing, an empty string, an empty row and an empty table are empty. Other values,
+    /// including strings of only whitespace, never are.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            UntaggedValue::Primitive(Primitive::Nothing) => true,
+            UntaggedValue::Primitive(Primitive::String(string)) => string.is_empty(),
+            UntaggedValue::Row(row) => row.is_empty(),
+            UntaggedValue::Table(table) => table.is_empty(),
+            _ => false,
+        }
+    }
+
     pub fn expect_error(&self) -> ShellError {
         match self {
             UntaggedValue::Error(err) => err.clone(),
@@ -169,8 +181,8 @@ impl UntaggedValue {
         UntaggedValue::Primitive(Primitive::Boolean(s.into()))
     }
 
-    pub fn duration(secs: u64) -> UntaggedValue {
-        UntaggedValue::Primitive(Primitive::Duration(secs))
+    pub fn duration(nanos: impl Into<BigInt>) -> UntaggedValue {
+        UntaggedValue::Primitive(Primitive::Duration(nanos.into()))
     }
 
     pub fn system_date(s: SystemTime) -> UntaggedValue {
@@ -244,12 +256,29 @@ impl Value {
         }
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
     pub fn as_u64(&self) -> Result<u64, ShellError> {
         match &self.value {
             UntaggedValue::Primitive(primitive) => primitive.as_u64(self.tag.span),
             _ => Err(ShellError::type_error("integer", self.spanned_type_name())),
         }
     }
+
+    /// Merges two rows, with the columns of `other` taking precedence over the columns of `self`.
+    pub fn merge(&self, other: &Value) -> Result<Value, ShellError> {
+        match (&self.value, &other.value) {
+            (UntaggedValue::Row(this), UntaggedValue::Row(that)) => {
+                Ok(UntaggedValue::Row(this.merge(that)).into_value(&self.tag))
+            }
+            (UntaggedValue::Row(_), _) => {
+                Err(ShellError::type_error("row", other.spanned_type_name()))
+            }
+            _ => Err(ShellError::type_error("row", self.spanned_type_name())),
+        }
+    }
 }
 
 impl Into<UntaggedValue> for &str {
@@ -305,3 +334,55 @@ impl From<String> for UntaggedValue {
         UntaggedValue::Primitive(Primitive::String(input))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Dictionary, UntaggedValue, Value};
+    use indexmap::IndexMap;
+
+    fn row(entries: Vec<(&str, Value)>) -> Value {
+        let mut map = IndexMap::new();
+
+        for (key, value) in entries {
+            map.insert(key.to_string(), value);
+        }
+
+        UntaggedValue::Row(Dictionary::new(map)).into_untagged_value()
+    }
+
+    fn table(list: Vec<Value>) -> Value {
+        UntaggedValue::table(&list).into_untagged_value()
+    }
+
+    fn string(input: &str) -> Value {
+        UntaggedValue::string(input).into_untagged_value()
+    }
+
+    #[test]
+    fn nothing_is_empty() {
+        assert!(UntaggedValue::nothing().into_untagged_value().is_empty());
+    }
+
+    #[test]
+    fn strings_are_empty_only_without_any_characters() {
+        assert!(string("").is_empty());
+        assert!(!string("   ").is_empty());
+        assert!(!string("nu").is_empty());
+    }
+
+    #[test]
+    fn rows_and_tables_are_empty_without_entries() {
+        assert!(row(vec![]).is_empty());
+        assert!(!row(vec![("name", string(""))]).is_empty());
+        assert!(table(vec![]).is_empty());
+        assert!(!table(vec![string("")]).is_empty());
+    }
+
+    #[test]
+    fn numbers_and_booleans_are_never_empty() {
+        assert!(!UntaggedValue::int(0).into_untagged_value().is_empty());
+        assert!(!UntaggedValue::boolean(false)
+            .into_untagged_value()
+            .is_empty());
+    }
+}
@@ -9,6 +9,12 @@ use serde::{Deserialize, Serialize};
 pub enum UnspannedPathMember {
     String(String),
     Int(BigInt),
+    Wildcard,
+    /// A slice of rows, from `from` up to but not including `to`
+    Range {
+        from: BigInt,
+        to: BigInt,
+    },
 }
 
 impl UnspannedPathMember {
@@ -31,6 +37,8 @@ impl PrettyDebug for &PathMember {
         match &self.unspanned {
             UnspannedPathMember::String(string) => b::primitive(format!("{:?}", string)),
             UnspannedPathMember::Int(int) => b::primitive(format!("{}", int)),
+            UnspannedPathMember::Wildcard => b::primitive("*"),
+            UnspannedPathMember::Range { from, to } => b::primitive(format!("{}..{}", from, to)),
         }
     }
 }
@@ -51,6 +59,30 @@ impl ColumnPath {
     pub fn split_last(&self) -> (&PathMember, &[PathMember]) {
         self.members.split_last().unwrap()
     }
+
+    /// Returns a new path with `member` added after the existing members
+    pub fn append(&self, member: PathMember) -> ColumnPath {
+        let mut members = self.members.clone();
+        members.push(member);
+
+        ColumnPath::new(members)
+    }
+
+    /// Whether the first members of this path are those of `prefix`, compared pairwise by
+    /// value and kind; spans are ignored. Every path starts with the empty path and itself.
+    pub fn starts_with(&self, prefix: &ColumnPath) -> bool {
+        prefix.members.len() <= self.members.len()
+            && self
+                .members
+                .iter()
+                .zip(prefix.members.iter())
+                .all(|(member, other)| member.unspanned == other.unspanned)
+    }
+
+    /// Returns a new path with a column name added after the existing members
+    pub fn with_string(&self, string: &str, span: impl Into<Span>) -> ColumnPath {
+        self.append(PathMember::string(string, span))
+    }
 }
 
 impl PrettyDebug for ColumnPath {
@@ -85,12 +117,62 @@ impl PathMember {
     pub fn int(int: impl Into<BigInt>, span: impl Into<Span>) -> PathMember {
         UnspannedPathMember::Int(int.into()).into_path_member(span)
     }
+
+    pub fn wildcard(span: impl Into<Span>) -> PathMember {
+        UnspannedPathMember::Wildcard.into_path_member(span)
+    }
+
+    pub fn range(
+        from: impl Into<BigInt>,
+        to: impl Into<BigInt>,
+        span: impl Into<Span>,
+    ) -> PathMember {
+        UnspannedPathMember::Range {
+            from: from.into(),
+            to: to.into(),
+        }
+        .into_path_member(span)
+    }
+
+    pub fn is_string(&self) -> bool {
+        matches!(self.unspanned, UnspannedPathMember::String(_))
+    }
+
+    pub fn is_int(&self) -> bool {
+        matches!(self.unspanned, UnspannedPathMember::Int(_))
+    }
+
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self.unspanned, UnspannedPathMember::Wildcard)
+    }
+
+    pub fn is_range(&self) -> bool {
+        matches!(self.unspanned, UnspannedPathMember::Range { .. })
+    }
+
+    /// The column name, if the member is a string
+    pub fn as_string_member(&self) -> Option<&str> {
+        match &self.unspanned {
+            UnspannedPathMember::String(string) => Some(&string[..]),
+            _ => None,
+        }
+    }
+
+    /// The index, if the member is an integer
+    pub fn as_int_member(&self) -> Option<&BigInt> {
+        match &self.unspanned {
+            UnspannedPathMember::Int(int) => Some(int),
+            _ => None,
+        }
+    }
 }
 
 pub fn did_you_mean(obj_source: &Value, field_tried: &PathMember) -> Option<Vec<(usize, String)>> {
     let field_tried = match &field_tried.unspanned {
         UnspannedPathMember::String(string) => string.clone(),
         UnspannedPathMember::Int(int) => format!("{}", int),
+        UnspannedPathMember::Wildcard => "*".to_string(),
+        UnspannedPathMember::Range { from, to } => format!("{}..{}", from, to),
     };
 
     let possibilities = obj_source.data_descriptors();
@@ -112,3 +194,95 @@ pub fn did_you_mean(obj_source: &Value, field_tried: &PathMember) -> Option<Vec<
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ColumnPath, PathMember};
+    use nu_source::Span;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn classifies_string_members() {
+        let member = PathMember::string("name", Span::unknown());
+
+        assert!(member.is_string());
+        assert!(!member.is_int());
+        assert!(!member.is_wildcard());
+        assert!(!member.is_range());
+        assert_eq!(member.as_string_member(), Some("name"));
+        assert_eq!(member.as_int_member(), None);
+    }
+
+    #[test]
+    fn classifies_int_members() {
+        let member = PathMember::int(3, Span::unknown());
+
+        assert!(!member.is_string());
+        assert!(member.is_int());
+        assert!(!member.is_wildcard());
+        assert!(!member.is_range());
+        assert_eq!(member.as_string_member(), None);
+        assert_eq!(member.as_int_member(), Some(&BigInt::from(3)));
+    }
+
+    #[test]
+    fn classifies_wildcard_and_range_members() {
+        let wildcard = PathMember::wildcard(Span::unknown());
+        let range = PathMember::range(1, 3, Span::unknown());
+
+        assert!(wildcard.is_wildcard());
+        assert!(!wildcard.is_range());
+        assert!(range.is_range());
+        assert!(!range.is_int());
+        assert_eq!(wildcard.as_string_member(), None);
+        assert_eq!(range.as_int_member(), None);
+    }
+
+    fn path(members: Vec<PathMember>) -> ColumnPath {
+        ColumnPath::new(members)
+    }
+
+    #[test]
+    fn starts_with_a_shorter_prefix() {
+        let full = path(vec![
+            PathMember::string("package", Span::new(0, 7)),
+            PathMember::int(0, Span::new(8, 9)),
+            PathMember::string("name", Span::new(10, 14)),
+        ]);
+        let prefix = path(vec![
+            PathMember::string("package", Span::unknown()),
+            PathMember::int(0, Span::unknown()),
+        ]);
+
+        assert!(full.starts_with(&prefix));
+        assert!(!prefix.starts_with(&full));
+        assert!(full.starts_with(&path(vec![])));
+    }
+
+    #[test]
+    fn starts_with_a_path_of_equal_length() {
+        let one = path(vec![
+            PathMember::string("package", Span::new(0, 7)),
+            PathMember::string("name", Span::new(8, 12)),
+        ]);
+        let other = path(vec![
+            PathMember::string("package", Span::unknown()),
+            PathMember::string("name", Span::unknown()),
+        ]);
+
+        assert!(one.starts_with(&other));
+        assert!(!one.starts_with(&path(vec![
+            PathMember::string("package", Span::unknown()),
+            PathMember::string("version", Span::unknown()),
+        ])));
+    }
+
+    #[test]
+    fn does_not_start_with_a_member_of_another_kind() {
+        let by_index = path(vec![PathMember::int(0, Span::unknown())]);
+        let by_name = path(vec![PathMember::string("0", Span::unknown())]);
+
+        assert!(!by_index.starts_with(&by_name));
+        assert!(!by_name.starts_with(&by_index));
+    }
+}
@@ -4,6 +4,7 @@ use crate::value::{UntaggedValue, Value};
 use derive_new::new;
 use getset::Getters;
 use indexmap::IndexMap;
+use nu_errors::ShellError;
 use nu_source::{b, DebugDocBuilder, PrettyDebug, Spanned, Tag};
 use serde::{Deserialize, Serialize};
 use std::cmp::{Ord, Ordering, PartialOrd};
@@ -84,17 +85,17 @@ impl PrettyDebug for Dictionary {
 
 impl From<IndexMap<String, Value>> for Dictionary {
     fn from(input: IndexMap<String, Value>) -> Dictionary {
-        let mut out = IndexMap::default();
-
-        for (key, value) in input {
-            out.insert(key, value);
-        }
-
-        Dictionary::new(out)
+        Dictionary::new(input)
     }
 }
 
 impl Dictionary {
+    /// Builds a dictionary whose entries are in the order the pairs are given. A repeated key
+    /// keeps the position of its first occurrence and the value of its last.
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (String, Value)>) -> Dictionary {
+        Dictionary::new(pairs.into_iter().collect())
+    }
+
     pub fn get_data(&self, desc: &String) -> MaybeOwned<'_, Value> {
         match self.entries.get(desc) {
             Some(v) => MaybeOwned::Borrowed(v),
@@ -108,12 +109,20 @@ impl Dictionary {
         self.entries.keys()
     }
 
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn contains_key(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
     pub fn get_data_by_key(&self, name: Spanned<&str>) -> Option<Value> {
-        let result = self
-            .entries
-            .iter()
-            .find(|(desc_name, _)| *desc_name == name.item)?
-            .1;
+        let result = self.entries.get(name.item)?;
 
         Some(
             result
@@ -124,19 +133,92 @@ impl Dictionary {
     }
 
     pub fn get_mut_data_by_key(&mut self, name: &str) -> Option<&mut Value> {
-        match self
-            .entries
-            .iter_mut()
-            .find(|(desc_name, _)| *desc_name == name)
-        {
-            Some((_, v)) => Some(v),
-            None => None,
-        }
+        self.entries.get_mut(name)
     }
 
     pub fn insert_data_at_key(&mut self, name: &str, value: Value) {
         self.entries.insert(name.to_string(), value);
     }
+
+    /// Returns a new dictionary with the entries of `other` laid over the entries of `self`.
+    /// Keys already present keep their position, keys only found in `other` are appended.
+    pub fn merge(&self, other: &Dictionary) -> Dictionary {
+        let mut entries = self.entries.clone();
+
+        for (key, value) in other.entries.iter() {
+            entries.insert(key.clone(), value.clone());
+        }
+
+        Dictionary::new(entries)
+    }
+
+    /// Removes the entry for `name`, keeping the remaining entries in their original order.
+    pub fn remove_key(&mut self, name: &str) -> Option<Value> {
+        self.entries.shift_remove(name)
+    }
+
+    /// Renames the key `from` to `to`, keeping the entry at its original position.
+    ///
+    /// Errors if `from` is not present, or if `to` already names a different entry,
+    /// so that renaming never silently drops a column.
+    pub fn rename_key(&mut self, from: &str, to: &str) -> Result<(), ShellError> {
+        if !self.contains_key(from) {
+            return Err(ShellError::untagged_runtime_error(format!(
+                "Cannot rename \"{}\": no such key",
+                from
+            )));
+        }
+
+        if from == to {
+            return Ok(());
+        }
+
+        if self.contains_key(to) {
+            return Err(ShellError::untagged_runtime_error(format!(
+                "Cannot rename \"{}\" to \"{}\": the key already exists",
+                from, to
+            )));
+        }
+
+        let entries = std::mem::replace(&mut self.entries, IndexMap::default());
+
+        self.entries = entries
+            .into_iter()
+            .map(|(key, value)| {
+                if key == from {
+                    (to.to_string(), value)
+                } else {
+                    (key, value)
+                }
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    /// Returns a new dictionary with the entries ordered by key, comparing keys case-sensitively.
+    pub fn sort_keys(&self) -> Dictionary {
+        let mut entries = self.entries.clone();
+        entries.sort_keys();
+
+        Dictionary::new(entries)
+    }
+
+    /// Like `sort_keys`, but ignores case. Keys that only differ by case keep their relative order.
+    pub fn sort_keys_insensitive(&self) -> Dictionary {
+        let mut entries = self.entries.clone();
+        entries.sort_by(|left, _, right, _| left.to_lowercase().cmp(&right.to_lowercase()));
+
+        Dictionary::new(entries)
+    }
+
+    /// Iterates the entries ordered by key, like `sort_keys`, but without cloning any values.
+    pub fn iter_sorted(&self) -> impl Iterator<Item = (&String, &Value)> {
+        let mut entries: Vec<(&String, &Value)> = self.entries.iter().collect();
+        entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+        entries.into_iter()
+    }
 }
 
 #[derive(Debug)]
@@ -194,3 +276,242 @@ impl From<TaggedDictBuilder> for Value {
         input.into_value()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Dictionary;
+    use crate::value::{UntaggedValue, Value};
+    use indexmap::IndexMap;
+
+    fn string(input: &str) -> Value {
+        UntaggedValue::string(input).into_untagged_value()
+    }
+
+    fn dict(entries: &[(&str, &str)]) -> Dictionary {
+        let mut map = IndexMap::new();
+
+        for (key, value) in entries {
+            map.insert(key.to_string(), string(value));
+        }
+
+        Dictionary::new(map)
+    }
+
+    fn keys(dict: &Dictionary) -> Vec<&str> {
+        dict.keys().map(|key| &key[..]).collect()
+    }
+
+    #[test]
+    fn merge_overlays_overlapping_keys_in_place() {
+        let left = dict(&[("name", "nu"), ("version", "0.7"), ("license", "MIT")]);
+        let right = dict(&[("version", "0.8"), ("edition", "2018")]);
+
+        let merged = left.merge(&right);
+
+        assert_eq!(keys(&merged), vec!["name", "version", "license", "edition"]);
+        assert_eq!(merged.entries["version"], string("0.8"));
+        assert_eq!(merged.entries["edition"], string("2018"));
+    }
+
+    #[test]
+    fn merge_appends_disjoint_keys() {
+        let left = dict(&[("name", "nu")]);
+        let right = dict(&[("version", "0.7")]);
+
+        let merged = left.merge(&right);
+
+        assert_eq!(keys(&merged), vec!["name", "version"]);
+        assert_eq!(merged.entries["name"], string("nu"));
+        assert_eq!(merged.entries["version"], string("0.7"));
+    }
+
+    #[test]
+    fn merge_with_empty_dictionaries() {
+        let row = dict(&[("name", "nu"), ("version", "0.7")]);
+        let empty = Dictionary::default();
+
+        assert_eq!(keys(&row.merge(&empty)), vec!["name", "version"]);
+        assert_eq!(keys(&empty.merge(&row)), vec!["name", "version"]);
+        assert!(empty.merge(&empty).entries.is_empty());
+    }
+
+    #[test]
+    fn merge_rows_through_values() {
+        let left = UntaggedValue::Row(dict(&[("name", "nu")])).into_untagged_value();
+        let right = UntaggedValue::Row(dict(&[("version", "0.7")])).into_untagged_value();
+
+        let merged = left.merge(&right).unwrap();
+
+        assert_eq!(
+            merged,
+            UntaggedValue::Row(dict(&[("name", "nu"), ("version", "0.7")])).into_untagged_value()
+        );
+        assert!(left.merge(&string("nu")).is_err());
+    }
+
+    #[test]
+    fn remove_key_keeps_the_remaining_order() {
+        let mut row = dict(&[("name", "nu"), ("version", "0.7"), ("license", "MIT")]);
+
+        assert_eq!(row.remove_key("version"), Some(string("0.7")));
+        assert_eq!(keys(&row), vec!["name", "license"]);
+        assert_eq!(row.remove_key("version"), None);
+    }
+
+    #[test]
+    fn rename_key_keeps_the_original_slot() {
+        let mut row = dict(&[("name", "nu"), ("version", "0.7"), ("license", "MIT")]);
+
+        row.rename_key("version", "release").unwrap();
+
+        assert_eq!(keys(&row), vec!["name", "release", "license"]);
+        assert_eq!(row.entries["release"], string("0.7"));
+    }
+
+    #[test]
+    fn rename_key_errors_on_missing_or_existing_keys() {
+        let mut row = dict(&[("name", "nu"), ("version", "0.7")]);
+
+        assert!(row.rename_key("license", "licence").is_err());
+        assert!(row.rename_key("name", "version").is_err());
+        assert_eq!(keys(&row), vec!["name", "version"]);
+        assert!(row.rename_key("name", "name").is_ok());
+    }
+
+    #[test]
+    fn sort_keys_orders_entries_by_key() {
+        let row = dict(&[("version", "0.7"), ("Name", "nu"), ("license", "MIT")]);
+
+        let sorted = row.sort_keys();
+
+        assert_eq!(keys(&sorted), vec!["Name", "license", "version"]);
+        assert_eq!(sorted.entries["Name"], string("nu"));
+        assert_eq!(sorted.entries["license"], string("MIT"));
+        assert_eq!(sorted.entries["version"], string("0.7"));
+        assert_eq!(keys(&row), vec!["version", "Name", "license"]);
+    }
+
+    #[test]
+    fn sort_keys_insensitive_ignores_case() {
+        let row = dict(&[("version", "0.7"), ("Name", "nu"), ("license", "MIT")]);
+
+        let sorted = row.sort_keys_insensitive();
+
+        assert_eq!(keys(&sorted), vec!["license", "Name", "version"]);
+        assert_eq!(sorted.entries["Name"], string("nu"));
+    }
+
+    #[test]
+    fn sort_keys_moves_tags_with_values() {
+        use nu_source::{Span, Tag};
+
+        let mut map = IndexMap::new();
+        map.insert(
+            "b".to_string(),
+            UntaggedValue::string("second").into_value(Tag::from(Span::new(5, 11))),
+        );
+        map.insert(
+            "a".to_string(),
+            UntaggedValue::string("first").into_value(Tag::from(Span::new(0, 5))),
+        );
+
+        let sorted = Dictionary::new(map).sort_keys();
+
+        assert_eq!(keys(&sorted), vec!["a", "b"]);
+        assert_eq!(sorted.entries["a"].tag.span, Span::new(0, 5));
+        assert_eq!(sorted.entries["b"].tag.span, Span::new(5, 11));
+    }
+
+    #[test]
+    fn get_data_by_key_retags_with_the_requested_span() {
+        use nu_source::{Span, SpannedItem, Tag};
+
+        let mut map = IndexMap::new();
+        map.insert(
+            "name".to_string(),
+            UntaggedValue::string("nu").into_value(Tag::from(Span::new(0, 4))),
+        );
+        let mut row = Dictionary::new(map);
+
+        let found = row
+            .get_data_by_key("name".spanned(Span::new(10, 14)))
+            .unwrap();
+
+        assert_eq!(found.value, string("nu").value);
+        assert_eq!(found.tag.span, Span::new(10, 14));
+        assert!(row
+            .get_data_by_key("version".spanned(Span::new(10, 17)))
+            .is_none());
+        assert!(row.get_mut_data_by_key("name").is_some());
+        assert!(row.get_mut_data_by_key("version").is_none());
+    }
+
+    #[test]
+    fn iterates_entries_in_key_order() {
+        let row = dict(&[("version", "0.7"), ("Name", "nu"), ("edition", "2018")]);
+
+        let sorted: Vec<(&str, &Value)> = row
+            .iter_sorted()
+            .map(|(key, value)| (&key[..], value))
+            .collect();
+
+        assert_eq!(
+            sorted,
+            vec![
+                ("Name", &string("nu")),
+                ("edition", &string("2018")),
+                ("version", &string("0.7")),
+            ]
+        );
+        assert_eq!(keys(&row), vec!["version", "Name", "edition"]);
+    }
+
+    #[test]
+    fn contains_key_checks_for_present_keys_only() {
+        let row = dict(&[("name", "nu"), ("version", "0.7")]);
+
+        assert!(row.contains_key("name"));
+        assert!(row.contains_key("version"));
+        assert!(!row.contains_key("Name"));
+        assert!(!row.contains_key("license"));
+        assert!(!Dictionary::default().contains_key("name"));
+    }
+
+    #[test]
+    fn len_counts_the_entries() {
+        let empty = Dictionary::default();
+        let row = dict(&[("name", "nu"), ("version", "0.7")]);
+
+        assert_eq!(empty.len(), 0);
+        assert!(empty.is_empty());
+        assert_eq!(row.len(), 2);
+        assert!(!row.is_empty());
+    }
+
+    #[test]
+    fn from_pairs_keeps_the_insertion_order() {
+        let pairs = vec!["name", "version", "authors", "edition", "license"]
+            .into_iter()
+            .map(|key| (key.to_string(), string(key)));
+
+        let row = Dictionary::from_pairs(pairs);
+
+        assert_eq!(
+            keys(&row),
+            vec!["name", "version", "authors", "edition", "license"]
+        );
+        assert_eq!(row.entries["edition"], string("edition"));
+    }
+
+    #[test]
+    fn from_pairs_keeps_the_first_position_of_a_repeated_key() {
+        let row = Dictionary::from_pairs(vec![
+            ("name".to_string(), string("nu")),
+            ("version".to_string(), string("0.7")),
+            ("name".to_string(), string("nushell")),
+        ]);
+
+        assert_eq!(keys(&row), vec!["name", "version"]);
+        assert_eq!(row.entries["name"], string("nushell"));
+    }
+}
@@ -9,6 +9,7 @@ use nu_errors::{ExpectedRange, ShellError};
 use nu_source::{PrettyDebug, Span, SpannedItem};
 use num_bigint::BigInt;
 use num_traits::cast::{FromPrimitive, ToPrimitive};
+use num_traits::{Signed, Zero};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -26,7 +27,10 @@ pub enum Primitive {
     Pattern(String),
     Boolean(bool),
     Date(DateTime<Utc>),
-    Duration(u64), // Duration in seconds
+    /// A duration in nanoseconds, unbounded so that long spans like `1000y` still fit.
+    /// Serializers such as `to-json` write this count of nanoseconds out as is.
+    #[serde(with = "serde_bigint")]
+    Duration(BigInt),
     Range(Box<Range>),
     Path(PathBuf),
     #[serde(with = "serde_bytes")]
@@ -111,7 +115,7 @@ pub fn format_primitive(primitive: &Primitive, field_name: Option<&String>) -> S
                 _ => byte.format(1).to_string(),
             }
         }
-        Primitive::Duration(sec) => format_duration(*sec),
+        Primitive::Duration(nanos) => format_duration(nanos),
         Primitive::Int(i) => i.to_string(),
         Primitive::Decimal(decimal) => decimal.to_string(),
         Primitive::Range(range) => format!(
@@ -154,16 +158,84 @@ pub fn format_primitive(primitive: &Primitive, field_name: Option<&String>) -> S
     }
 }
 
-pub fn format_duration(sec: u64) -> String {
+pub const NANOS_PER_SEC: u64 = 1_000_000_000;
+const NANOS_PER_DAY: u64 = 86_400 * NANOS_PER_SEC;
+
+/// Splits a non-negative duration into whole days, which can be arbitrarily many, and the
+/// nanoseconds left over, which always fit in a `u64`
+fn split_days(nanos: &BigInt) -> (BigInt, u64) {
+    let per_day = BigInt::from(NANOS_PER_DAY);
+    let rest = (nanos % &per_day)
+        .to_u64()
+        .expect("less than a day of nanoseconds fits in a u64");
+
+    (nanos / per_day, rest)
+}
+
+pub fn format_duration(nanos: &BigInt) -> String {
+    if nanos.is_negative() {
+        return format!("-{}", format_duration(&-nanos));
+    }
+
+    let (days, rest) = split_days(nanos);
+
+    // Durations under a second are shown in the largest sub-second unit that fits
+    if days.is_zero() {
+        match rest {
+            n if n >= NANOS_PER_SEC => {}
+            n if n >= 1_000_000 => return format!("{} ms", n / 1_000_000),
+            n if n >= 1_000 => return format!("{} us", n / 1_000),
+            n => return format!("{} ns", n),
+        }
+    }
+
+    let sec = rest / NANOS_PER_SEC;
     let (minutes, seconds) = (sec / 60, sec % 60);
     let (hours, minutes) = (minutes / 60, minutes % 60);
-    let (days, hours) = (hours / 24, hours % 24);
-
-    match (days, hours, minutes, seconds) {
-        (0, 0, 0, 1) => "1 sec".to_owned(),
-        (0, 0, 0, s) => format!("{} secs", s),
-        (0, 0, m, s) => format!("{}:{:02}", m, s),
-        (0, h, m, s) => format!("{}:{:02}:{:02}", h, m, s),
-        (d, h, m, s) => format!("{}:{:02}:{:02}:{:02}", d, h, m, s),
+
+    match (days.is_zero(), hours, minutes, seconds) {
+        (true, 0, 0, 1) => "1 sec".to_owned(),
+        (true, 0, 0, s) => format!("{} secs", s),
+        (true, 0, m, s) => format!("{}:{:02}", m, s),
+        (true, h, m, s) => format!("{}:{:02}:{:02}", h, m, s),
+        (false, h, m, s) => format!("{}:{:02}:{:02}:{:02}", days, h, m, s),
+    }
+}
+
+/// Format a duration with unit suffixes, like `1h30m`, skipping the units that are zero
+pub fn format_compact_duration(nanos: &BigInt) -> String {
+    if nanos.is_zero() {
+        return "0s".to_owned();
+    }
+
+    if nanos.is_negative() {
+        return format!("-{}", format_compact_duration(&-nanos));
     }
+
+    let units = [
+        ("h", 3_600 * NANOS_PER_SEC),
+        ("m", 60 * NANOS_PER_SEC),
+        ("s", NANOS_PER_SEC),
+        ("ms", 1_000_000),
+        ("us", 1_000),
+        ("ns", 1),
+    ];
+
+    let (days, mut remaining) = split_days(nanos);
+    let mut out = String::new();
+
+    if !days.is_zero() {
+        out.push_str(&format!("{}d", days));
+    }
+
+    for (suffix, size) in units.iter() {
+        let amount = remaining / size;
+        remaining %= size;
+
+        if amount > 0 {
+            out.push_str(&format!("{}{}", amount, suffix));
+        }
+    }
+
+    out
 }
@@ -1,22 +1,30 @@
+use bigdecimal::BigDecimal;
 use itertools::Itertools;
 use nu_errors::{ExpectedRange, ShellError};
+use nu_parser::{Number, Unit};
 use nu_protocol::{
-    ColumnPath, MaybeOwned, PathMember, Primitive, ShellTypeName, SpannedTypeName,
-    UnspannedPathMember, UntaggedValue, Value,
+    did_you_mean, format_compact_duration, ColumnPath, Dictionary, MaybeOwned, PathMember,
+    Primitive, ShellTypeName, SpannedTypeName, UnspannedPathMember, UntaggedValue, Value,
 };
-use nu_source::{HasSpan, PrettyDebug, Spanned, SpannedItem, Tag, Tagged, TaggedItem};
+use nu_source::{HasSpan, PrettyDebug, Span, Spanned, SpannedItem, Tag, Tagged, TaggedItem};
 use num_traits::cast::ToPrimitive;
+use num_traits::Signed;
+use std::cmp::Ordering;
 
 pub trait ValueExt {
     fn into_parts(self) -> (UntaggedValue, Tag);
     fn get_data(&self, desc: &String) -> MaybeOwned<'_, Value>;
     fn get_data_by_key(&self, name: Spanned<&str>) -> Option<Value>;
+    fn get_data_by_key_sparse(&self, name: Spanned<&str>) -> Option<Value>;
     fn get_data_by_member(&self, name: &PathMember) -> Result<Value, ShellError>;
     fn get_data_by_column_path(
         &self,
         path: &ColumnPath,
         callback: Box<dyn FnOnce((&Value, &PathMember, ShellError)) -> ShellError>,
     ) -> Result<Value, ShellError>;
+    fn get_data_by_column_path_simple(&self, path: &ColumnPath) -> Result<Value, ShellError>;
+    fn get_data_by_column_path_ref(&self, path: &ColumnPath) -> Option<&Value>;
+    fn get_data_by_path_str(&self, path: &str) -> Result<Value, ShellError>;
     fn insert_data_at_path(&self, path: &str, new_value: Value) -> Option<Value>;
     fn insert_data_at_member(
         &mut self,
@@ -28,14 +36,34 @@ pub trait ValueExt {
         split_path: &ColumnPath,
         new_value: Value,
     ) -> Result<Value, ShellError>;
+    fn insert_data_at_column_path_creating(
+        &self,
+        split_path: &ColumnPath,
+        new_value: Value,
+    ) -> Result<Value, ShellError>;
     fn replace_data_at_column_path(
         &self,
         split_path: &ColumnPath,
         replaced_value: Value,
     ) -> Option<Value>;
+    fn replace_or_insert_data_at_column_path(
+        &self,
+        split_path: &ColumnPath,
+        replaced_value: Value,
+    ) -> Option<Value>;
+    fn remove_data_at_column_path(&self, split_path: &ColumnPath) -> Option<Value>;
+    fn flatten(&self) -> Value;
+    fn map_leaves<F: Fn(&Primitive) -> Primitive>(&self, f: F) -> Value;
+    fn walk<F: FnMut(&ColumnPath, &Value)>(&self, f: F);
     fn as_column_path(&self) -> Result<Tagged<ColumnPath>, ShellError>;
     fn as_path_member(&self) -> Result<PathMember, ShellError>;
     fn as_string(&self) -> Result<String, ShellError>;
+    fn as_string_strict(&self) -> Result<&str, ShellError>;
+    fn as_string_lossy(&self) -> String;
+    fn format_bytes(&self) -> Option<String>;
+    fn as_number(&self) -> Result<Number, ShellError>;
+    fn compare(&self, other: &Value) -> Result<Ordering, ShellError>;
+    fn deep_eq(&self, other: &Value) -> bool;
 }
 
 impl ValueExt for Value {
@@ -51,6 +79,10 @@ impl ValueExt for Value {
         get_data_by_key(self, name)
     }
 
+    fn get_data_by_key_sparse(&self, name: Spanned<&str>) -> Option<Value> {
+        get_data_by_key_sparse(self, name)
+    }
+
     fn get_data_by_member(&self, name: &PathMember) -> Result<Value, ShellError> {
         get_data_by_member(self, name)
     }
@@ -63,6 +95,18 @@ impl ValueExt for Value {
         get_data_by_column_path(self, path, callback)
     }
 
+    fn get_data_by_column_path_simple(&self, path: &ColumnPath) -> Result<Value, ShellError> {
+        get_data_by_column_path_simple(self, path)
+    }
+
+    fn get_data_by_column_path_ref(&self, path: &ColumnPath) -> Option<&Value> {
+        get_data_by_column_path_ref(self, path)
+    }
+
+    fn get_data_by_path_str(&self, path: &str) -> Result<Value, ShellError> {
+        get_data_by_path_str(self, path)
+    }
+
     fn insert_data_at_path(&self, path: &str, new_value: Value) -> Option<Value> {
         insert_data_at_path(self, path, new_value)
     }
@@ -83,6 +127,14 @@ impl ValueExt for Value {
         insert_data_at_column_path(self, split_path, new_value)
     }
 
+    fn insert_data_at_column_path_creating(
+        &self,
+        split_path: &ColumnPath,
+        new_value: Value,
+    ) -> Result<Value, ShellError> {
+        insert_data_at_column_path_creating(self, split_path, new_value)
+    }
+
     fn replace_data_at_column_path(
         &self,
         split_path: &ColumnPath,
@@ -91,6 +143,30 @@ impl ValueExt for Value {
         replace_data_at_column_path(self, split_path, replaced_value)
     }
 
+    fn replace_or_insert_data_at_column_path(
+        &self,
+        split_path: &ColumnPath,
+        replaced_value: Value,
+    ) -> Option<Value> {
+        replace_or_insert_data_at_column_path(self, split_path, replaced_value)
+    }
+
+    fn remove_data_at_column_path(&self, split_path: &ColumnPath) -> Option<Value> {
+        remove_data_at_column_path(self, split_path)
+    }
+
+    fn flatten(&self) -> Value {
+        flatten(self)
+    }
+
+    fn map_leaves<F: Fn(&Primitive) -> Primitive>(&self, f: F) -> Value {
+        map_leaves(self, f)
+    }
+
+    fn walk<F: FnMut(&ColumnPath, &Value)>(&self, f: F) {
+        walk(self, f)
+    }
+
     fn as_column_path(&self) -> Result<Tagged<ColumnPath>, ShellError> {
         as_column_path(self)
     }
@@ -102,6 +178,30 @@ impl ValueExt for Value {
     fn as_string(&self) -> Result<String, ShellError> {
         as_string(self)
     }
+
+    fn as_string_strict(&self) -> Result<&str, ShellError> {
+        as_string_strict(self)
+    }
+
+    fn as_string_lossy(&self) -> String {
+        as_string_lossy(self)
+    }
+
+    fn format_bytes(&self) -> Option<String> {
+        format_bytes(self)
+    }
+
+    fn as_number(&self) -> Result<Number, ShellError> {
+        as_number(self)
+    }
+
+    fn compare(&self, other: &Value) -> Result<Ordering, ShellError> {
+        compare(self, other)
+    }
+
+    fn deep_eq(&self, other: &Value) -> bool {
+        deep_eq(self, other)
+    }
 }
 
 pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, ShellError> {
@@ -112,9 +212,10 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
             UnspannedPathMember::String(string) => o
                 .get_data_by_key(string[..].spanned(name.span))
                 .ok_or_else(|| {
-                    ShellError::missing_property(
+                    ShellError::missing_property_with_suggestion(
                         "row".spanned(value.tag.span),
                         string.spanned(name.span),
+                        closest_column(value, name),
                     )
                 }),
 
@@ -123,6 +224,19 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
                 "row".spanned(value.tag.span),
                 name.span,
             )),
+
+            // If the member is a wildcard, get every column
+            UnspannedPathMember::Wildcard => {
+                Ok(UntaggedValue::Table(o.entries.values().cloned().collect())
+                    .into_value(Tag::new(value.anchor(), name.span)))
+            }
+
+            // If the member is a range, it's an error, since a row has no rows to take
+            UnspannedPathMember::Range { .. } => Err(ShellError::labeled_error(
+                "Range on a row",
+                "ranges can only be taken from a table",
+                name.span,
+            )),
         },
 
         // If the value is a table
@@ -145,9 +259,10 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
                     }
 
                     if out.is_empty() {
-                        Err(ShellError::missing_property(
+                        Err(ShellError::missing_property_with_suggestion(
                             "table".spanned(value.tag.span),
                             string.spanned(name.span),
+                            closest_column(value, name),
                         ))
                     } else {
                         Ok(UntaggedValue::Table(out)
@@ -155,15 +270,9 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
                     }
                 }
                 UnspannedPathMember::Int(int) => {
-                    let index = int.to_usize().ok_or_else(|| {
-                        ShellError::range_error(
-                            ExpectedRange::Usize,
-                            &"massive integer".spanned(name.span),
-                            "indexing",
-                        )
-                    })?;
-
-                    match get_data_by_index(value, index.spanned(value.tag.span)) {
+                    match table_index(int, l.len())
+                        .and_then(|index| get_data_by_index(value, index.spanned(value.tag.span)))
+                    {
                         Some(v) => Ok(v.clone()),
                         None => Err(ShellError::range_error(
                             0..(l.len()),
@@ -172,6 +281,26 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
                         )),
                     }
                 }
+
+                // If the member is a wildcard, get every row
+                UnspannedPathMember::Wildcard => {
+                    Ok(UntaggedValue::Table(l.clone())
+                        .into_value(Tag::new(value.anchor(), name.span)))
+                }
+
+                // If the member is a range, get the rows from its start up to (but not
+                // including) its end, clamped to the rows available
+                UnspannedPathMember::Range { from, to } => {
+                    let from = range_bound(from, l.len(), name.span)?;
+                    let to = range_bound(to, l.len(), name.span)?;
+                    let rows = if from < to {
+                        l[from..to].to_vec()
+                    } else {
+                        vec![]
+                    };
+
+                    Ok(UntaggedValue::Table(rows).into_value(Tag::new(value.anchor(), name.span)))
+                }
             }
         }
         other => Err(ShellError::type_error(
@@ -181,6 +310,46 @@ pub fn get_data_by_member(value: &Value, name: &PathMember) -> Result<Value, She
     }
 }
 
+/// The existing column with the smallest edit distance to the one tried, to suggest instead,
+/// unless even that one is more than a third of the tried name's length (rounded up) away
+fn closest_column(value: &Value, tried: &PathMember) -> Option<String> {
+    let max_distance = (tried.display().chars().count() + 2) / 3;
+
+    did_you_mean(value, tried)?
+        .into_iter()
+        .next()
+        .filter(|(distance, _)| *distance <= max_distance)
+        .map(|(_, column)| column)
+}
+
+/// Resolves an index into a table of `len` rows, where negative indices count back from the end
+fn table_index(int: &(impl Signed + ToPrimitive), len: usize) -> Option<usize> {
+    if int.is_negative() {
+        int.abs()
+            .to_usize()
+            .and_then(|from_end| len.checked_sub(from_end))
+    } else {
+        int.to_usize()
+    }
+}
+
+/// Resolves a bound of a range of rows into a table of `len` rows, clamped to the rows available
+fn range_bound(
+    bound: &(impl Signed + ToPrimitive),
+    len: usize,
+    span: Span,
+) -> Result<usize, ShellError> {
+    if bound.is_negative() {
+        Err(ShellError::labeled_error(
+            "Negative range bound",
+            "ranges count rows from the start of the table",
+            span,
+        ))
+    } else {
+        Ok(bound.to_usize().unwrap_or(len).min(len))
+    }
+}
+
 pub fn get_data_by_column_path(
     value: &Value,
     path: &ColumnPath,
@@ -188,7 +357,31 @@ pub fn get_data_by_column_path(
 ) -> Result<Value, ShellError> {
     let mut current = value.clone();
 
-    for p in path.iter() {
+    for (idx, p) in path.iter().enumerate() {
+        if p.is_wildcard() {
+            // The rest of the path is followed from every row, skipping the rows lacking
+            // one of its columns
+            let rest = ColumnPath::new(path.members()[idx + 1..].to_vec());
+            let rows = match get_data_by_member(&current, p) {
+                Ok(rows) => rows,
+                Err(e) => return Err(callback((&current.clone(), &p.clone(), e))),
+            };
+
+            let mut out = vec![];
+
+            if let UntaggedValue::Table(l) = &rows.value {
+                for row in l {
+                    match get_data_by_column_path(row, &rest, Box::new(|(_, _, e)| e)) {
+                        Ok(v) => out.push(v),
+                        Err(e) if e.is_missing_property() => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+
+            return Ok(UntaggedValue::Table(out).into_value(&rows.tag));
+        }
+
         let value = get_data_by_member(&current, p);
 
         match value {
@@ -200,49 +393,117 @@ pub fn get_data_by_column_path(
     Ok(current)
 }
 
-pub fn insert_data_at_path(value: &Value, path: &str, new_value: Value) -> Option<Value> {
-    let mut new_obj = value.clone();
+/// Like `get_data_by_column_path`, but passes the error of the failing member through unchanged.
+pub fn get_data_by_column_path_simple(
+    value: &Value,
+    path: &ColumnPath,
+) -> Result<Value, ShellError> {
+    get_data_by_column_path(value, path, Box::new(|(_, _, error)| error))
+}
 
-    let split_path: Vec<_> = path.split('.').collect();
+/// Follows the column path by reference, without cloning anything along the way.
+///
+/// Only members that lead to a value already stored in `value` can be followed: column
+/// names on rows and indices into tables. Members that would have to build a new value
+/// (a column name or a wildcard on a table, or a range) give `None`, as does a missing
+/// member. The leaf keeps its own tag rather than being retagged with the path's span.
+pub fn get_data_by_column_path_ref<'value>(
+    value: &'value Value,
+    path: &ColumnPath,
+) -> Option<&'value Value> {
+    let mut current = value;
 
-    if let UntaggedValue::Row(ref mut o) = new_obj.value {
-        let mut current = o;
+    for member in path.iter() {
+        current = get_data_by_member_ref(current, member)?;
+    }
 
-        if split_path.len() == 1 {
-            // Special case for inserting at the top level
-            current.entries.insert(
-                path.to_string(),
-                new_value.value.clone().into_value(&value.tag),
-            );
-            return Some(new_obj);
-        }
-
-        for idx in 0..split_path.len() {
-            match current.entries.get_mut(split_path[idx]) {
-                Some(next) => {
-                    if idx == (split_path.len() - 2) {
-                        if let UntaggedValue::Row(o) = &mut next.value {
-                            o.entries.insert(
-                                split_path[idx + 1].to_string(),
-                                new_value.value.clone().into_value(&value.tag),
-                            );
-                        }
-                        return Some(new_obj.clone());
-                    } else {
-                        match next.value {
-                            UntaggedValue::Row(ref mut o) => {
-                                current = o;
-                            }
-                            _ => return None,
-                        }
-                    }
+    Some(current)
+}
+
+pub(crate) fn get_data_by_member_ref<'value>(
+    value: &'value Value,
+    name: &PathMember,
+) -> Option<&'value Value> {
+    match (&value.value, &name.unspanned) {
+        (UntaggedValue::Row(o), UnspannedPathMember::String(string)) => o.entries.get(string),
+        (UntaggedValue::Table(l), UnspannedPathMember::Int(int)) => {
+            l.get(table_index(int, l.len())?)
+        }
+        _ => None,
+    }
+}
+
+/// Reads a dotted path such as `"user.name"`, where every part is a column name.
+///
+/// A dot preceded by a backslash is part of the name, so `log\.level` reads the single
+/// column `log.level`.
+pub fn get_data_by_path_str(value: &Value, path: &str) -> Result<Value, ShellError> {
+    let members = split_path_str(path)
+        .into_iter()
+        .map(|member| PathMember::string(member, value.tag.span))
+        .collect();
+
+    get_data_by_column_path_simple(value, &ColumnPath::new(members))
+}
+
+/// Splits a dotted path into its column names. Shared by every `_path` reader and writer.
+///
+/// `\.` is a literal dot and `\\` a literal backslash; any other backslash is kept as is.
+fn split_path_str(path: &str) -> Vec<String> {
+    let mut members = vec![];
+    let mut current = String::new();
+    let mut chars = path.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.as_str().chars().next() {
+                Some(escaped @ '.') | Some(escaped @ '\\') => {
+                    current.push(escaped);
+                    chars.next();
                 }
-                _ => return None,
+                _ => current.push(c),
+            },
+            '.' => {
+                members.push(current);
+                current = String::new();
             }
+            _ => current.push(c),
         }
     }
 
-    None
+    members.push(current);
+    members
+}
+
+pub fn insert_data_at_path(value: &Value, path: &str, new_value: Value) -> Option<Value> {
+    let mut new_obj = value.clone();
+
+    let mut split_path = split_path_str(path);
+    let last = split_path.pop()?;
+
+    let mut current = match &mut new_obj.value {
+        UntaggedValue::Row(o) => o,
+        _ => return None,
+    };
+
+    for key in split_path {
+        // Missing intermediate columns are created as empty rows
+        let next = current
+            .entries
+            .entry(key)
+            .or_insert_with(|| UntaggedValue::Row(Dictionary::default()).into_value(&value.tag));
+
+        current = match &mut next.value {
+            UntaggedValue::Row(o) => o,
+            _ => return None,
+        };
+    }
+
+    current
+        .entries
+        .insert(last, new_value.value.into_value(&value.tag));
+
+    Some(new_obj)
 }
 
 pub fn insert_data_at_member(
@@ -260,6 +521,14 @@ pub fn insert_data_at_member(
                 "column name",
                 "integer".spanned(member.span),
             )),
+            UnspannedPathMember::Wildcard => Err(ShellError::type_error(
+                "column name",
+                "wildcard".spanned(member.span),
+            )),
+            UnspannedPathMember::Range { .. } => Err(ShellError::type_error(
+                "column name",
+                "range".spanned(member.span),
+            )),
         },
         UntaggedValue::Table(array) => match &member.unspanned {
             UnspannedPathMember::String(_) => Err(ShellError::type_error(
@@ -278,13 +547,23 @@ pub fn insert_data_at_member(
                 insert_data_at_index(array, int.tagged(member.span), new_value.clone())?;
                 Ok(())
             }
+            UnspannedPathMember::Wildcard => Err(ShellError::type_error(
+                "list index",
+                "wildcard".spanned(member.span),
+            )),
+            UnspannedPathMember::Range { .. } => Err(ShellError::type_error(
+                "list index",
+                "range".spanned(member.span),
+            )),
         },
         other => match &member.unspanned {
             UnspannedPathMember::String(_) => Err(ShellError::type_error(
                 "row",
                 other.type_name().spanned(value.span()),
             )),
-            UnspannedPathMember::Int(_) => Err(ShellError::type_error(
+            UnspannedPathMember::Int(_)
+            | UnspannedPathMember::Wildcard
+            | UnspannedPathMember::Range { .. } => Err(ShellError::type_error(
                 "table",
                 other.type_name().spanned(value.span()),
             )),
@@ -296,6 +575,29 @@ pub fn insert_data_at_column_path(
     value: &Value,
     split_path: &ColumnPath,
     new_value: Value,
+) -> Result<Value, ShellError> {
+    insert_data_at_column_path_with(value, split_path, new_value, get_mut_data_by_member)
+}
+
+/// Like `insert_data_at_column_path`, but missing intermediate columns are created as empty rows
+pub fn insert_data_at_column_path_creating(
+    value: &Value,
+    split_path: &ColumnPath,
+    new_value: Value,
+) -> Result<Value, ShellError> {
+    insert_data_at_column_path_with(
+        value,
+        split_path,
+        new_value,
+        get_or_create_mut_data_by_member,
+    )
+}
+
+fn insert_data_at_column_path_with(
+    value: &Value,
+    split_path: &ColumnPath,
+    new_value: Value,
+    step: for<'value> fn(&'value mut Value, &PathMember) -> Option<&'value mut Value>,
 ) -> Result<Value, ShellError> {
     let (last, front) = split_path.split_last();
     let mut original = value.clone();
@@ -305,7 +607,7 @@ pub fn insert_data_at_column_path(
     for member in front {
         let type_name = current.spanned_type_name();
 
-        current = get_mut_data_by_member(current, &member).ok_or_else(|| {
+        current = step(current, &member).ok_or_else(|| {
             ShellError::missing_property(
                 member.plain_string(std::usize::MAX).spanned(member.span),
                 type_name,
@@ -346,6 +648,120 @@ pub fn replace_data_at_column_path(
     None
 }
 
+pub fn replace_or_insert_data_at_column_path(
+    value: &Value,
+    split_path: &ColumnPath,
+    replaced_value: Value,
+) -> Option<Value> {
+    let mut new_obj: Value = value.clone();
+    let mut current = &mut new_obj;
+
+    for member in split_path.iter() {
+        current = get_or_create_mut_data_by_member(current, member)?;
+    }
+
+    *current = replaced_value.value.into_value(&value.tag);
+
+    Some(new_obj)
+}
+
+pub fn remove_data_at_column_path(value: &Value, split_path: &ColumnPath) -> Option<Value> {
+    let (last, front) = split_path.members().split_last()?;
+    let mut new_obj: Value = value.clone();
+    let mut current = &mut new_obj;
+
+    for member in front {
+        current = get_mut_data_by_member(current, member)?;
+    }
+
+    match (&mut current.value, &last.unspanned) {
+        (UntaggedValue::Row(o), UnspannedPathMember::String(key)) => {
+            // Shifting keeps the remaining columns in their original order
+            o.entries.shift_remove(key)?;
+        }
+        (UntaggedValue::Table(l), UnspannedPathMember::Int(int)) => {
+            let index = int.to_usize().filter(|index| *index < l.len())?;
+            l.remove(index);
+        }
+        _ => return None,
+    }
+
+    Some(new_obj)
+}
+
+/// Splices the elements of any nested table into its parent table, one level deep.
+/// Values other than tables are returned unchanged.
+pub fn flatten(value: &Value) -> Value {
+    match &value.value {
+        UntaggedValue::Table(l) => {
+            let mut out = vec![];
+
+            for item in l {
+                match &item.value {
+                    UntaggedValue::Table(inner) => out.extend(inner.iter().cloned()),
+                    _ => out.push(item.clone()),
+                }
+            }
+
+            UntaggedValue::Table(out).into_value(&value.tag)
+        }
+        _ => value.clone(),
+    }
+}
+
+/// Rebuilds the value with `f` applied to every primitive inside it, however deeply nested
+/// in rows and tables. Every rebuilt value keeps its tag; blocks and errors are left as is.
+pub fn map_leaves<F: Fn(&Primitive) -> Primitive>(value: &Value, f: F) -> Value {
+    map_leaves_with(value, &f)
+}
+
+fn map_leaves_with<F: Fn(&Primitive) -> Primitive>(value: &Value, f: &F) -> Value {
+    match &value.value {
+        UntaggedValue::Primitive(primitive) => {
+            UntaggedValue::Primitive(f(primitive)).into_value(&value.tag)
+        }
+        UntaggedValue::Row(o) => {
+            let mut out = Dictionary::default();
+
+            for (key, item) in o.entries.iter() {
+                out.insert_data_at_key(key, map_leaves_with(item, f));
+            }
+
+            UntaggedValue::Row(out).into_value(&value.tag)
+        }
+        UntaggedValue::Table(l) => {
+            UntaggedValue::Table(l.iter().map(|item| map_leaves_with(item, f)).collect())
+                .into_value(&value.tag)
+        }
+        UntaggedValue::Error(_) | UntaggedValue::Block(_) => value.clone(),
+    }
+}
+
+/// Visits the value and everything nested inside it depth-first, parents before their children,
+/// calling `f` with the path from the top (empty for the value itself) and the node at it.
+/// Row columns add string members to the path and table rows add int members.
+pub fn walk<F: FnMut(&ColumnPath, &Value)>(value: &Value, mut f: F) {
+    walk_with(value, &ColumnPath::new(vec![]), &mut f)
+}
+
+fn walk_with<F: FnMut(&ColumnPath, &Value)>(value: &Value, path: &ColumnPath, f: &mut F) {
+    f(path, value);
+
+    match &value.value {
+        UntaggedValue::Row(o) => {
+            for (key, item) in o.entries.iter() {
+                walk_with(item, &path.with_string(key, item.tag.span), f);
+            }
+        }
+        UntaggedValue::Table(l) => {
+            for (index, item) in l.iter().enumerate() {
+                walk_with(item, &path.append(PathMember::int(index, item.tag.span)), f);
+            }
+        }
+        _ => {}
+    }
+}
+
 pub fn as_column_path(value: &Value) -> Result<Tagged<ColumnPath>, ShellError> {
     match &value.value {
         UntaggedValue::Table(table) => {
@@ -398,6 +814,8 @@ pub fn as_string(value: &Value) -> Result<String, ShellError> {
         UntaggedValue::Primitive(Primitive::Int(x)) => Ok(format!("{}", x)),
         UntaggedValue::Primitive(Primitive::Bytes(x)) => Ok(format!("{}", x)),
         UntaggedValue::Primitive(Primitive::Path(x)) => Ok(format!("{}", x.display())),
+        UntaggedValue::Primitive(Primitive::Date(x)) => Ok(x.to_rfc3339()),
+        UntaggedValue::Primitive(Primitive::Duration(x)) => Ok(format_compact_duration(x)),
         UntaggedValue::Primitive(Primitive::ColumnPath(path)) => {
             Ok(path.iter().map(|member| member.display()).join("."))
         }
@@ -411,20 +829,169 @@ pub fn as_string(value: &Value) -> Result<String, ShellError> {
     }
 }
 
+/// Borrows the text of a string, erroring on every other type. Unlike `as_string`, nothing is
+/// converted, so commands that only make sense on text can reject numbers, booleans and the like.
+pub fn as_string_strict(value: &Value) -> Result<&str, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::String(s)) => Ok(s),
+        _ => Err(ShellError::type_error("string", value.spanned_type_name())),
+    }
+}
+
+/// Formats a byte size in the largest decimal unit (B, KB, MB, ...) it holds at least one of,
+/// with one decimal place for anything bigger than bytes. Returns `None` for other values.
+pub fn format_bytes(value: &Value) -> Option<String> {
+    const UNITS: [Unit; 6] = [
+        Unit::Byte,
+        Unit::Kilobyte,
+        Unit::Megabyte,
+        Unit::Gigabyte,
+        Unit::Terabyte,
+        Unit::Petabyte,
+    ];
+
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Bytes(bytes)) => {
+            let bytes = u128::from(*bytes);
+            let unit = UNITS
+                .iter()
+                .rev()
+                .find(|unit| bytes >= unit.base_units())
+                .unwrap_or(&Unit::Byte);
+
+            Some(match unit {
+                Unit::Byte => format!("{} {}", bytes, unit.as_str()),
+                _ => format!(
+                    "{:.1} {}",
+                    bytes as f64 / unit.base_units() as f64,
+                    unit.as_str()
+                ),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Reads an int or a decimal as a `Number`. Byte sizes and durations are numbers with a unit,
+/// so they are rejected along with every other type rather than silently losing the unit.
+pub fn as_number(value: &Value) -> Result<Number, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Int(int)) => Ok(Number::Int(int.clone())),
+        UntaggedValue::Primitive(Primitive::Decimal(decimal)) => {
+            Ok(Number::Decimal(decimal.clone()))
+        }
+        _ => Err(ShellError::type_error("number", value.spanned_type_name())),
+    }
+}
+
+/// Renders any value as a string, never failing. Tables become `[a, b]`, rows become
+/// `{key: value}` and values with no textual form become their type name in angle brackets.
+pub fn as_string_lossy(value: &Value) -> String {
+    match &value.value {
+        UntaggedValue::Row(o) => format!(
+            "{{{}}}",
+            o.entries
+                .iter()
+                .map(|(key, value)| format!("{}: {}", key, as_string_lossy(value)))
+                .join(", ")
+        ),
+        UntaggedValue::Table(l) => format!("[{}]", l.iter().map(as_string_lossy).join(", ")),
+        UntaggedValue::Primitive(Primitive::Nothing) => String::new(),
+        UntaggedValue::Primitive(Primitive::Line(s)) => s.clone(),
+        UntaggedValue::Primitive(Primitive::Pattern(s)) => s.clone(),
+        _ => as_string(value).unwrap_or_else(|_| format!("<{}>", value.type_name())),
+    }
+}
+
+/// Orders two primitives of comparable types: numbers numerically (an int against a decimal
+/// compares their values), strings lexically, `false` before `true`, and dates, durations and
+/// byte sizes chronologically or by size. Any other pair of types is a coercion error.
+pub fn compare(left: &Value, right: &Value) -> Result<Ordering, ShellError> {
+    let ordering = match (&left.value, &right.value) {
+        (UntaggedValue::Primitive(left), UntaggedValue::Primitive(right)) => {
+            compare_primitives(left, right)
+        }
+        _ => None,
+    };
+
+    ordering.ok_or_else(|| {
+        ShellError::coerce_error(left.spanned_type_name(), right.spanned_type_name())
+    })
+}
+
+fn compare_primitives(left: &Primitive, right: &Primitive) -> Option<Ordering> {
+    match (left, right) {
+        (Primitive::Int(left), Primitive::Int(right)) => Some(left.cmp(right)),
+        (Primitive::Int(left), Primitive::Decimal(right)) => {
+            Some(BigDecimal::from(left.clone()).cmp(right))
+        }
+        (Primitive::Decimal(left), Primitive::Int(right)) => {
+            Some(left.cmp(&BigDecimal::from(right.clone())))
+        }
+        (Primitive::Decimal(left), Primitive::Decimal(right)) => Some(left.cmp(right)),
+        (Primitive::Boolean(left), Primitive::Boolean(right)) => Some(left.cmp(right)),
+        (Primitive::Bytes(left), Primitive::Bytes(right)) => Some(left.cmp(right)),
+        (Primitive::Date(left), Primitive::Date(right)) => Some(left.cmp(right)),
+        (Primitive::Duration(left), Primitive::Duration(right)) => Some(left.cmp(right)),
+        _ => Some(text(left)?.cmp(text(right)?)),
+    }
+}
+
+fn text(primitive: &Primitive) -> Option<&str> {
+    match primitive {
+        Primitive::String(s) | Primitive::Line(s) => Some(s),
+        _ => None,
+    }
+}
+
+/// Whether two values hold the same data, ignoring the tags (and so spans and anchors) of every
+/// value inside them. Rows are equal when they have the same columns in any order; tables must
+/// have equal rows in the same order.
+pub fn deep_eq(left: &Value, right: &Value) -> bool {
+    match (&left.value, &right.value) {
+        (UntaggedValue::Row(left), UntaggedValue::Row(right)) => {
+            left.len() == right.len()
+                && left
+                    .entries
+                    .iter()
+                    .all(|(key, value)| match right.entries.get(key) {
+                        Some(other) => deep_eq(value, other),
+                        None => false,
+                    })
+        }
+        (UntaggedValue::Table(left), UntaggedValue::Table(right)) => {
+            left.len() == right.len()
+                && left
+                    .iter()
+                    .zip(right.iter())
+                    .all(|(value, other)| deep_eq(value, other))
+        }
+        (
+            UntaggedValue::Primitive(Primitive::ColumnPath(left)),
+            UntaggedValue::Primitive(Primitive::ColumnPath(right)),
+        ) => left.starts_with(right) && right.starts_with(left),
+        (left, right) => left == right,
+    }
+}
+
 fn insert_data_at_index(
     list: &mut Vec<Value>,
     index: Tagged<usize>,
     new_value: Value,
 ) -> Result<(), ShellError> {
-    if list.len() >= index.item {
+    if index.item < list.len() {
+        list[index.item] = new_value;
+        Ok(())
+    } else if index.item == list.len() {
+        // Inserting just past the end appends
+        list.push(new_value);
+        Ok(())
+    } else {
         Err(ShellError::range_error(
-            0..(list.len()),
+            0..(list.len() + 1),
             &format_args!("{}", index.item).spanned(index.tag.span),
             "insert at index",
         ))
-    } else {
-        list[index.item] = new_value;
-        Ok(())
     }
 }
 
@@ -453,21 +1020,37 @@ pub(crate) fn get_data_by_index(value: &Value, idx: Spanned<usize>) -> Option<Va
     }
 }
 
+/// Gets the column `name` from a row, or from every row of a table. Table entries that
+/// lack the column, or aren't rows, are padded with nothing so the result lines up with
+/// the table.
 pub fn get_data_by_key(value: &Value, name: Spanned<&str>) -> Option<Value> {
+    get_data_by_key_padded(value, name, true)
+}
+
+/// Like `get_data_by_key`, but table entries that lack the column, or aren't rows, are
+/// skipped instead of padded, so absent columns can be told apart from present nothings.
+pub fn get_data_by_key_sparse(value: &Value, name: Spanned<&str>) -> Option<Value> {
+    get_data_by_key_padded(value, name, false)
+}
+
+fn get_data_by_key_padded(value: &Value, name: Spanned<&str>, pad: bool) -> Option<Value> {
     match &value.value {
         UntaggedValue::Row(o) => o.get_data_by_key(name),
         UntaggedValue::Table(l) => {
             let mut out = vec![];
             for item in l {
-                match item {
+                let found = match item {
                     Value {
                         value: UntaggedValue::Row(o),
                         ..
-                    } => match o.get_data_by_key(name) {
-                        Some(v) => out.push(v),
-                        None => out.push(UntaggedValue::nothing().into_untagged_value()),
-                    },
-                    _ => out.push(UntaggedValue::nothing().into_untagged_value()),
+                    } => o.get_data_by_key(name),
+                    _ => None,
+                };
+
+                match found {
+                    Some(v) => out.push(v),
+                    None if pad => out.push(UntaggedValue::nothing().into_untagged_value()),
+                    None => {}
                 }
             }
 
@@ -481,14 +1064,35 @@ pub fn get_data_by_key(value: &Value, name: Spanned<&str>) -> Option<Value> {
     }
 }
 
-pub(crate) fn get_mut_data_by_member<'value>(
+pub(crate) fn get_or_create_mut_data_by_member<'value>(
+    value: &'value mut Value,
+    name: &PathMember,
+) -> Option<&'value mut Value> {
+    // Missing columns of a row are created as empty rows
+    if let (UntaggedValue::Row(o), UnspannedPathMember::String(string)) =
+        (&mut value.value, &name.unspanned)
+    {
+        if !o.contains_key(string) {
+            o.insert_data_at_key(
+                string,
+                UntaggedValue::Row(Dictionary::default()).into_value(name.span),
+            );
+        }
+    }
+
+    get_mut_data_by_member(value, name)
+}
+
+pub(crate) fn get_mut_data_by_member<'value>(
     value: &'value mut Value,
     name: &PathMember,
 ) -> Option<&'value mut Value> {
     match &mut value.value {
         UntaggedValue::Row(o) => match &name.unspanned {
             UnspannedPathMember::String(string) => o.get_mut_data_by_key(&string),
-            UnspannedPathMember::Int(_) => None,
+            UnspannedPathMember::Int(_)
+            | UnspannedPathMember::Wildcard
+            | UnspannedPathMember::Range { .. } => None,
         },
         UntaggedValue::Table(l) => match &name.unspanned {
             UnspannedPathMember::String(string) => {
@@ -509,7 +1113,933 @@ pub(crate) fn get_mut_data_by_member<'value>(
                 let index = int.to_usize()?;
                 l.get_mut(index)
             }
+            UnspannedPathMember::Wildcard | UnspannedPathMember::Range { .. } => None,
         },
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nu_protocol::NANOS_PER_SEC;
+    use nu_source::Span;
+
+    fn int(i: i64) -> Value {
+        UntaggedValue::int(i).into_untagged_value()
+    }
+
+    fn decimal(input: &str) -> Value {
+        UntaggedValue::decimal(input.parse::<bigdecimal::BigDecimal>().unwrap())
+            .into_untagged_value()
+    }
+
+    fn boolean(b: bool) -> Value {
+        UntaggedValue::boolean(b).into_untagged_value()
+    }
+
+    fn index(i: usize) -> Tagged<usize> {
+        i.tagged_unknown()
+    }
+
+    fn table(list: Vec<Value>) -> Value {
+        UntaggedValue::table(&list).into_untagged_value()
+    }
+
+    fn member(i: i64) -> PathMember {
+        PathMember::int(i, Span::unknown())
+    }
+
+    #[test]
+    fn gets_the_last_row_with_index_minus_one() {
+        let table = table(vec![int(1), int(2), int(3)]);
+
+        assert_eq!(get_data_by_member(&table, &member(-1)).unwrap(), int(3));
+    }
+
+    #[test]
+    fn integer_index_errors_on_rows_underline_the_member() {
+        let row = UntaggedValue::Row(Dictionary::default()).into_value(Span::new(0, 10));
+        let diagnostic = get_data_by_member(&row, &PathMember::int(0, Span::new(11, 12)))
+            .unwrap_err()
+            .to_diagnostic();
+
+        assert_eq!(diagnostic.labels[0].span, Span::new(11, 12));
+        assert_eq!(diagnostic.labels[1].span, Span::new(0, 10));
+    }
+
+    #[test]
+    fn walks_every_path_parents_first() {
+        let dep = row(vec![("name", string("nu-source"))]);
+        let value = row(vec![
+            ("name", string("nu")),
+            ("deps", table(vec![dep, int(1)])),
+        ]);
+
+        let mut paths = vec![];
+        walk(&value, |path, _| paths.push(path.clone()));
+
+        let name = || PathMember::string("name", Span::unknown());
+        let deps = || PathMember::string("deps", Span::unknown());
+        let index = |i: usize| PathMember::int(i, Span::unknown());
+
+        assert_eq!(
+            paths,
+            vec![
+                path(vec![]),
+                path(vec![name()]),
+                path(vec![deps()]),
+                path(vec![deps(), index(0)]),
+                path(vec![deps(), index(0), name()]),
+                path(vec![deps(), index(1)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn gets_the_first_row_with_minus_the_length() {
+        let table = table(vec![int(1), int(2), int(3)]);
+
+        assert_eq!(get_data_by_member(&table, &member(-3)).unwrap(), int(1));
+    }
+
+    #[test]
+    fn negative_indices_past_the_start_error() {
+        let table = table(vec![int(1), int(2), int(3)]);
+
+        assert!(get_data_by_member(&table, &member(-4)).is_err());
+    }
+
+    fn row(entries: Vec<(&str, Value)>) -> Value {
+        let mut dict = Dictionary::default();
+
+        for (key, value) in entries {
+            dict.insert_data_at_key(key, value);
+        }
+
+        UntaggedValue::Row(dict).into_untagged_value()
+    }
+
+    fn path(members: Vec<PathMember>) -> ColumnPath {
+        ColumnPath::new(members)
+    }
+
+    #[test]
+    fn wildcard_collects_a_column_from_every_row() {
+        let rows = table(vec![
+            row(vec![("name", int(1))]),
+            row(vec![("other", int(2))]),
+            row(vec![("name", int(3))]),
+        ]);
+
+        let names = get_data_by_column_path(
+            &rows,
+            &path(vec![
+                PathMember::wildcard(Span::unknown()),
+                PathMember::string("name", Span::unknown()),
+            ]),
+            Box::new(|(_, _, e)| e),
+        )
+        .unwrap();
+
+        assert_eq!(names, table(vec![int(1), int(3)]));
+    }
+
+    #[test]
+    fn wildcard_skips_every_row_lacking_the_column() {
+        let rows = table(vec![row(vec![("other", int(1))])]);
+
+        let names = get_data_by_column_path(
+            &rows,
+            &path(vec![
+                PathMember::wildcard(Span::unknown()),
+                PathMember::string("name", Span::unknown()),
+            ]),
+            Box::new(|(_, _, e)| e),
+        )
+        .unwrap();
+
+        assert_eq!(names, table(vec![]));
+    }
+
+    #[test]
+    fn wildcard_fails_on_a_row_the_rest_of_the_path_cannot_be_read_from() {
+        let rows = table(vec![row(vec![("name", int(1))]), string("not a row")]);
+
+        let names = get_data_by_column_path(
+            &rows,
+            &path(vec![
+                PathMember::wildcard(Span::unknown()),
+                PathMember::string("name", Span::unknown()),
+            ]),
+            Box::new(|(_, _, e)| e),
+        );
+
+        assert!(names.is_err());
+    }
+
+    #[test]
+    fn reads_a_dotted_path_from_a_nested_row() {
+        let user = row(vec![("name", string("andres")), ("age", int(1))]);
+        let config = row(vec![("user", user.clone())]);
+
+        assert_eq!(
+            get_data_by_path_str(&config, "user.name").unwrap(),
+            string("andres")
+        );
+        assert_eq!(get_data_by_path_str(&config, "user").unwrap(), user);
+    }
+
+    #[test]
+    fn reading_a_missing_dotted_path_fails() {
+        let config = row(vec![("user", row(vec![("name", string("andres"))]))]);
+
+        assert!(get_data_by_path_str(&config, "user.email").is_err());
+        assert!(get_data_by_path_str(&config, "user.name.first").is_err());
+    }
+
+    #[test]
+    fn escaped_dots_are_part_of_the_column_name() {
+        let config = row(vec![("log.level", string("debug"))]);
+
+        assert_eq!(
+            get_data_by_path_str(&config, "log\\.level").unwrap(),
+            string("debug")
+        );
+        assert!(get_data_by_path_str(&config, "log.level").is_err());
+
+        assert_eq!(
+            insert_data_at_path(&row(vec![]), "log\\.level", string("debug")).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn escaped_and_unescaped_dots_can_be_mixed() {
+        let config = insert_data_at_path(&row(vec![]), "a.b\\.c.d", int(1)).unwrap();
+
+        assert_eq!(
+            config,
+            row(vec![("a", row(vec![("b.c", row(vec![("d", int(1))]))]))])
+        );
+        assert_eq!(get_data_by_path_str(&config, "a.b\\.c.d").unwrap(), int(1));
+    }
+
+    #[test]
+    fn splits_paths_on_unescaped_dots() {
+        assert_eq!(split_path_str("a.b"), vec!["a", "b"]);
+        assert_eq!(split_path_str("a\\.b"), vec!["a.b"]);
+        assert_eq!(split_path_str("a\\\\.b"), vec!["a\\", "b"]);
+        assert_eq!(split_path_str("a\\b"), vec!["a\\b"]);
+        assert_eq!(split_path_str("a.b\\."), vec!["a", "b."]);
+    }
+
+    #[test]
+    fn inserts_at_a_top_level_path() {
+        let config = row(vec![("name", int(1))]);
+
+        let config = insert_data_at_path(&config, "age", int(2)).unwrap();
+
+        assert_eq!(config, row(vec![("name", int(1)), ("age", int(2))]));
+    }
+
+    #[test]
+    fn inserts_into_an_existing_row_two_levels_deep() {
+        let config = row(vec![("git", row(vec![]))]);
+
+        let config = insert_data_at_path(&config, "git.user", int(1)).unwrap();
+
+        assert_eq!(config, row(vec![("git", row(vec![("user", int(1))]))]));
+    }
+
+    #[test]
+    fn inserts_three_levels_deep_when_the_first_two_exist() {
+        let config = row(vec![("config", row(vec![("git", row(vec![]))]))]);
+
+        let config = insert_data_at_path(&config, "config.git.user", int(1)).unwrap();
+
+        assert_eq!(
+            config,
+            row(vec![(
+                "config",
+                row(vec![("git", row(vec![("user", int(1))]))])
+            )])
+        );
+    }
+
+    #[test]
+    fn inserts_four_levels_deep_creating_missing_rows() {
+        let config = row(vec![("a", row(vec![]))]);
+
+        let config = insert_data_at_path(&config, "a.b.c.d", int(1)).unwrap();
+
+        assert_eq!(
+            config,
+            row(vec![(
+                "a",
+                row(vec![("b", row(vec![("c", row(vec![("d", int(1))]))]))])
+            )])
+        );
+    }
+
+    #[test]
+    fn cannot_insert_below_a_non_row_value() {
+        let config = row(vec![("a", int(1))]);
+
+        assert_eq!(insert_data_at_path(&config, "a.b.c", int(2)), None);
+    }
+
+    #[test]
+    fn inserting_at_a_column_path_requires_the_intermediate_rows() {
+        let config = row(vec![("a", row(vec![]))]);
+        let column_path = path(vec![
+            PathMember::string("a", Span::unknown()),
+            PathMember::string("b", Span::unknown()),
+            PathMember::string("c", Span::unknown()),
+        ]);
+
+        assert!(insert_data_at_column_path(&config, &column_path, int(1)).is_err());
+        assert_eq!(
+            insert_data_at_column_path_creating(&config, &column_path, int(1)).unwrap(),
+            row(vec![("a", row(vec![("b", row(vec![("c", int(1))]))]))])
+        );
+    }
+
+    #[test]
+    fn replace_or_insert_creates_a_fully_missing_path() {
+        let config = row(vec![]);
+
+        let config = replace_or_insert_data_at_column_path(
+            &config,
+            &path(vec![
+                PathMember::string("git", Span::unknown()),
+                PathMember::string("user", Span::unknown()),
+            ]),
+            int(1),
+        )
+        .unwrap();
+
+        assert_eq!(config, row(vec![("git", row(vec![("user", int(1))]))]));
+    }
+
+    #[test]
+    fn replace_or_insert_does_not_overwrite_an_intermediate_integer() {
+        let config = row(vec![("git", int(1))]);
+
+        let config = replace_or_insert_data_at_column_path(
+            &config,
+            &path(vec![
+                PathMember::string("git", Span::unknown()),
+                PathMember::string("user", Span::unknown()),
+            ]),
+            int(2),
+        );
+
+        assert_eq!(config, None);
+    }
+
+    #[test]
+    fn removing_a_middle_column_keeps_the_order_of_the_rest() {
+        let person = row(vec![("name", int(1)), ("age", int(2)), ("email", int(3))]);
+
+        let person = remove_data_at_column_path(
+            &person,
+            &path(vec![PathMember::string("age", Span::unknown())]),
+        )
+        .unwrap();
+
+        assert_eq!(person.data_descriptors(), vec!["name", "email"]);
+    }
+
+    #[test]
+    fn removes_a_nested_column() {
+        let config = row(vec![(
+            "git",
+            row(vec![
+                ("user", int(1)),
+                ("email", int(2)),
+                ("editor", int(3)),
+            ]),
+        )]);
+
+        let config = remove_data_at_column_path(
+            &config,
+            &path(vec![
+                PathMember::string("git", Span::unknown()),
+                PathMember::string("email", Span::unknown()),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            row(vec![(
+                "git",
+                row(vec![("user", int(1)), ("editor", int(3))])
+            )])
+        );
+    }
+
+    #[test]
+    fn removes_an_index_from_a_table() {
+        let rows = table(vec![int(1), int(2), int(3)]);
+
+        let rows = remove_data_at_column_path(&rows, &path(vec![member(1)])).unwrap();
+
+        assert_eq!(rows, table(vec![int(1), int(3)]));
+    }
+
+    #[test]
+    fn removing_a_missing_path_gives_nothing() {
+        let person = row(vec![("name", int(1))]);
+
+        assert_eq!(
+            remove_data_at_column_path(
+                &person,
+                &path(vec![PathMember::string("age", Span::unknown())]),
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn durations_are_strings_in_compact_form() {
+        let duration = UntaggedValue::duration(5400 * NANOS_PER_SEC).into_untagged_value();
+
+        assert_eq!(as_string(&duration).unwrap(), "1h30m");
+    }
+
+    #[test]
+    fn sub_second_durations_keep_their_smaller_units() {
+        let duration = UntaggedValue::duration(NANOS_PER_SEC + 2_000_003).into_untagged_value();
+
+        assert_eq!(as_string(&duration).unwrap(), "1s2ms3ns");
+    }
+
+    #[test]
+    fn durations_past_a_u64_of_nanoseconds_are_strings_in_days() {
+        // A thousand 365-day years, more than a u64 of nanoseconds can hold
+        let nanos = 365_000u128 * 86_400 * u128::from(NANOS_PER_SEC);
+        let duration = UntaggedValue::duration(nanos).into_untagged_value();
+
+        assert_eq!(as_string(&duration).unwrap(), "365000d");
+    }
+
+    #[test]
+    fn dates_are_strings_in_iso_8601() {
+        let date =
+            UntaggedValue::Primitive(Primitive::Date("2019-12-25T10:30:00Z".parse().unwrap()))
+                .into_untagged_value();
+
+        assert_eq!(as_string(&date).unwrap(), "2019-12-25T10:30:00+00:00");
+    }
+
+    #[test]
+    fn strict_strings_borrow_the_text() {
+        let text = UntaggedValue::string("nu").into_untagged_value();
+
+        assert_eq!(as_string_strict(&text).unwrap(), "nu");
+    }
+
+    #[test]
+    fn strict_strings_reject_a_boolean_the_lenient_version_accepts() {
+        let boolean = UntaggedValue::boolean(true).into_untagged_value();
+
+        assert_eq!(as_string(&boolean).unwrap(), "true");
+        assert_eq!(
+            as_string_strict(&boolean).unwrap_err(),
+            ShellError::type_error("string", boolean.spanned_type_name())
+        );
+    }
+
+    #[test]
+    fn inserts_at_index_zero_of_an_empty_list() {
+        let mut list = vec![];
+
+        assert!(insert_data_at_index(&mut list, index(0), int(1)).is_ok());
+        assert_eq!(list, vec![int(1)]);
+    }
+
+    #[test]
+    fn inserting_at_the_end_of_a_list_appends() {
+        let mut list = vec![int(1), int(2)];
+
+        assert!(insert_data_at_index(&mut list, index(2), int(3)).is_ok());
+        assert_eq!(list, vec![int(1), int(2), int(3)]);
+    }
+
+    #[test]
+    fn inserting_within_a_list_replaces() {
+        let mut list = vec![int(1), int(2)];
+
+        assert!(insert_data_at_index(&mut list, index(1), int(3)).is_ok());
+        assert_eq!(list, vec![int(1), int(3)]);
+    }
+
+    #[test]
+    fn inserting_well_past_the_end_of_a_list_errors() {
+        let mut list = vec![int(1), int(2)];
+
+        assert!(insert_data_at_index(&mut list, index(10), int(3)).is_err());
+        assert_eq!(list, vec![int(1), int(2)]);
+    }
+
+    #[test]
+    fn column_paths_built_incrementally_resolve_like_direct_ones() {
+        let value = row(vec![("package", row(vec![("version", int(7))]))]);
+
+        let direct = path(vec![
+            PathMember::string("package", Span::unknown()),
+            PathMember::string("version", Span::unknown()),
+        ]);
+        let package = path(vec![]).append(PathMember::string("package", Span::unknown()));
+        let built = package.with_string("version", Span::unknown());
+
+        assert_eq!(package.members().len(), 1);
+        assert_eq!(built, direct);
+        assert_eq!(
+            get_data_by_column_path(&value, &built, Box::new(|(_, _, e)| e)).unwrap(),
+            get_data_by_column_path(&value, &direct, Box::new(|(_, _, e)| e)).unwrap()
+        );
+    }
+
+    #[test]
+    fn simple_column_path_lookup_passes_the_missing_property_error_through() {
+        let value = row(vec![("package", row(vec![("version", int(7))]))]);
+
+        let found = get_data_by_column_path_simple(
+            &value,
+            &path(vec![
+                PathMember::string("package", Span::unknown()),
+                PathMember::string("version", Span::unknown()),
+            ]),
+        );
+
+        assert_eq!(found.unwrap(), int(7));
+
+        let missing = get_data_by_column_path_simple(
+            &value,
+            &path(vec![
+                PathMember::string("package", Span::unknown()),
+                PathMember::string("edition", Span::unknown()),
+            ]),
+        );
+
+        assert_eq!(
+            missing.unwrap_err(),
+            ShellError::missing_property_with_suggestion(
+                "row".spanned(Span::unknown()),
+                "edition".spanned(Span::unknown()),
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn missing_columns_suggest_the_closest_existing_one() {
+        let value = UntaggedValue::Row(Dictionary::from_pairs(vec![
+            ("name".to_string(), string("nu")),
+            ("version".to_string(), int(7)),
+        ]))
+        .into_value(Span::new(0, 4));
+
+        let diagnostic = get_data_by_member(&value, &PathMember::string("nmae", Span::new(5, 9)))
+            .unwrap_err()
+            .to_diagnostic();
+
+        assert_eq!(diagnostic.labels[1].span, Span::new(5, 9));
+        assert_eq!(
+            diagnostic.labels[1].message,
+            Some("did you mean 'name'?".to_string())
+        );
+    }
+
+    #[test]
+    fn range_gets_a_slice_of_rows() {
+        let rows = table(vec![int(0), int(1), int(2), int(3), int(4)]);
+
+        assert_eq!(
+            get_data_by_member(&rows, &PathMember::range(1, 3, Span::unknown())).unwrap(),
+            table(vec![int(1), int(2)])
+        );
+    }
+
+    #[test]
+    fn range_past_the_end_is_clamped_to_the_rows_available() {
+        let rows = table(vec![int(0), int(1), int(2)]);
+
+        assert_eq!(
+            get_data_by_member(&rows, &PathMember::range(1, 10, Span::unknown())).unwrap(),
+            table(vec![int(1), int(2)])
+        );
+        assert_eq!(
+            get_data_by_member(&rows, &PathMember::range(5, 10, Span::unknown())).unwrap(),
+            table(vec![])
+        );
+    }
+
+    #[test]
+    fn range_on_a_row_errors() {
+        let value = row(vec![("name", int(1))]);
+
+        let error = get_data_by_member(&value, &PathMember::range(0, 1, Span::unknown()))
+            .expect_err("a range can't be taken from a row");
+
+        assert_eq!(error.to_diagnostic().message, "Range on a row");
+    }
+
+    #[test]
+    fn range_with_a_negative_bound_errors() {
+        let rows = table(vec![int(0), int(1), int(2)]);
+
+        assert!(get_data_by_member(&rows, &PathMember::range(-1, 2, Span::unknown())).is_err());
+        assert!(get_data_by_member(&rows, &PathMember::range(0, -1, Span::unknown())).is_err());
+    }
+
+    #[test]
+    fn flattens_nested_tables_one_level_deep() {
+        let nested = table(vec![
+            int(1),
+            table(vec![int(2), table(vec![int(3)])]),
+            row(vec![("name", int(4))]),
+            table(vec![]),
+        ]);
+
+        assert_eq!(
+            flatten(&nested),
+            table(vec![
+                int(1),
+                int(2),
+                table(vec![int(3)]),
+                row(vec![("name", int(4))])
+            ])
+        );
+    }
+
+    #[test]
+    fn flattening_a_non_table_returns_it_unchanged() {
+        let value = row(vec![("name", table(vec![int(1)]))]);
+
+        assert_eq!(flatten(&value), value);
+        assert_eq!(flatten(&int(1)), int(1));
+    }
+
+    #[test]
+    fn borrowing_a_deeply_nested_leaf_matches_the_owned_lookup() {
+        let depth = 64;
+        let mut nested = int(42);
+        let mut members = vec![];
+
+        for level in (0..depth).rev() {
+            let name = format!("level{}", level);
+            nested = row(vec![(&name, table(vec![int(0), nested]))]);
+            members.push(PathMember::int(-1, Span::unknown()));
+            members.push(PathMember::string(name, Span::unknown()));
+        }
+
+        members.reverse();
+        let path = path(members);
+
+        let borrowed = get_data_by_column_path_ref(&nested, &path).unwrap();
+        let owned = get_data_by_column_path_simple(&nested, &path).unwrap();
+
+        assert_eq!(borrowed, &int(42));
+        assert_eq!(borrowed.value, owned.value);
+    }
+
+    #[test]
+    fn borrowing_lookups_give_none_for_missing_or_computed_members() {
+        let value = row(vec![("rows", table(vec![row(vec![("name", int(1))])]))]);
+
+        let missing = path(vec![PathMember::string("other", Span::unknown())]);
+        let computed = path(vec![
+            PathMember::string("rows", Span::unknown()),
+            PathMember::string("name", Span::unknown()),
+        ]);
+        let indexed = path(vec![
+            PathMember::string("rows", Span::unknown()),
+            PathMember::int(0, Span::unknown()),
+            PathMember::string("name", Span::unknown()),
+        ]);
+
+        assert_eq!(get_data_by_column_path_ref(&value, &missing), None);
+        assert_eq!(get_data_by_column_path_ref(&value, &computed), None);
+        assert_eq!(get_data_by_column_path_ref(&value, &indexed), Some(&int(1)));
+    }
+
+    fn mixed_table() -> Value {
+        table(vec![
+            row(vec![("name", int(1))]),
+            row(vec![("other", int(2))]),
+            int(3),
+            row(vec![(
+                "name",
+                UntaggedValue::nothing().into_untagged_value(),
+            )]),
+        ])
+    }
+
+    fn values(table: Value) -> Vec<UntaggedValue> {
+        match table.value {
+            UntaggedValue::Table(l) => l.into_iter().map(|v| v.value).collect(),
+            other => panic!("expected a table, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn gets_a_column_from_a_table_padding_with_nothing() {
+        let found = get_data_by_key(&mixed_table(), "name".spanned(Span::unknown())).unwrap();
+
+        assert_eq!(
+            values(found),
+            vec![
+                UntaggedValue::int(1),
+                UntaggedValue::nothing(),
+                UntaggedValue::nothing(),
+                UntaggedValue::nothing()
+            ]
+        );
+    }
+
+    #[test]
+    fn gets_a_column_from_a_table_skipping_rows_without_it() {
+        let found =
+            get_data_by_key_sparse(&mixed_table(), "name".spanned(Span::unknown())).unwrap();
+
+        assert_eq!(
+            values(found),
+            vec![UntaggedValue::int(1), UntaggedValue::nothing()]
+        );
+        assert_eq!(
+            get_data_by_key_sparse(&mixed_table(), "missing".spanned(Span::unknown())),
+            None
+        );
+    }
+
+    fn string(input: &str) -> Value {
+        UntaggedValue::string(input).into_untagged_value()
+    }
+
+    #[test]
+    fn renders_a_row_as_key_value_pairs() {
+        let value = row(vec![
+            ("name", string("nu")),
+            ("version", int(7)),
+            ("missing", UntaggedValue::nothing().into_untagged_value()),
+        ]);
+
+        assert_eq!(as_string_lossy(&value), "{name: nu, version: 7, missing: }");
+    }
+
+    #[test]
+    fn renders_a_small_table_in_brackets() {
+        let value = table(vec![
+            int(1),
+            string("two"),
+            row(vec![("three", int(3))]),
+            table(vec![]),
+        ]);
+
+        assert_eq!(as_string_lossy(&value), "[1, two, {three: 3}, []]");
+    }
+
+    #[test]
+    fn renders_values_without_a_textual_form_by_type() {
+        let value =
+            UntaggedValue::Primitive(Primitive::Binary(vec![1, 2, 3])).into_untagged_value();
+
+        assert_eq!(as_string_lossy(&value), "<binary>");
+    }
+
+    fn uppercase(primitive: &Primitive) -> Primitive {
+        match primitive {
+            Primitive::String(s) => Primitive::String(s.to_uppercase()),
+            other => other.clone(),
+        }
+    }
+
+    #[test]
+    fn maps_every_string_in_nested_rows_and_tables() {
+        let value = row(vec![
+            ("name", string("nu")),
+            ("version", int(7)),
+            (
+                "authors",
+                table(vec![
+                    string("yehuda"),
+                    row(vec![("name", string("jonathan"))]),
+                ]),
+            ),
+        ]);
+
+        assert_eq!(
+            map_leaves(&value, uppercase),
+            row(vec![
+                ("name", string("NU")),
+                ("version", int(7)),
+                (
+                    "authors",
+                    table(vec![
+                        string("YEHUDA"),
+                        row(vec![("name", string("JONATHAN"))]),
+                    ]),
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn mapping_leaves_keeps_tags_and_errors() {
+        let tag = Tag::from(Span::new(2, 4));
+        let error =
+            UntaggedValue::Error(ShellError::untagged_runtime_error("boom")).into_untagged_value();
+        let value = UntaggedValue::table(&vec![
+            UntaggedValue::string("nu").into_value(&tag),
+            error.clone(),
+        ])
+        .into_value(&tag);
+
+        let mapped = map_leaves(&value, uppercase);
+
+        assert_eq!(mapped.tag, tag);
+        assert_eq!(
+            mapped,
+            UntaggedValue::table(&vec![UntaggedValue::string("NU").into_value(&tag), error])
+                .into_value(&tag)
+        );
+    }
+
+    #[test]
+    fn compares_ints_and_decimals_numerically() {
+        assert_eq!(compare(&int(2), &decimal("2.5")).unwrap(), Ordering::Less);
+        assert_eq!(
+            compare(&decimal("2.5"), &int(2)).unwrap(),
+            Ordering::Greater
+        );
+        assert_eq!(compare(&int(2), &decimal("2.0")).unwrap(), Ordering::Equal);
+        assert_eq!(compare(&int(10), &int(9)).unwrap(), Ordering::Greater);
+    }
+
+    #[test]
+    fn compares_strings_lexically() {
+        assert_eq!(
+            compare(&string("apple"), &string("banana")).unwrap(),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare(&string("10"), &string("9")).unwrap(),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare(&string("nu"), &string("nu")).unwrap(),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn compares_false_before_true() {
+        assert_eq!(
+            compare(&boolean(false), &boolean(true)).unwrap(),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn comparing_mismatched_types_fails() {
+        assert!(compare(&string("2"), &int(2)).is_err());
+        assert!(compare(&boolean(true), &int(1)).is_err());
+        assert!(compare(&table(vec![]), &table(vec![])).is_err());
+    }
+
+    fn spanned(value: UntaggedValue, start: usize) -> Value {
+        value.into_value(Span::new(start, start + 2))
+    }
+
+    #[test]
+    fn values_differing_only_by_tag_are_deeply_equal() {
+        let mut dict = Dictionary::default();
+        dict.insert_data_at_key("name", spanned(UntaggedValue::string("nu"), 0));
+        dict.insert_data_at_key(
+            "deps",
+            spanned(
+                UntaggedValue::table(&vec![spanned(UntaggedValue::int(1), 3)]),
+                5,
+            ),
+        );
+        let tagged = spanned(UntaggedValue::Row(dict), 0);
+
+        let untagged = row(vec![("name", string("nu")), ("deps", table(vec![int(1)]))]);
+
+        assert_ne!(tagged, untagged);
+        assert!(deep_eq(&tagged, &untagged));
+    }
+
+    #[test]
+    fn rows_are_deeply_equal_whatever_their_key_order() {
+        let one = row(vec![("name", string("nu")), ("version", string("0.7"))]);
+        let other = row(vec![("version", string("0.7")), ("name", string("nu"))]);
+
+        assert!(deep_eq(&one, &other));
+        assert!(!deep_eq(&one, &row(vec![("name", string("nu"))])));
+        assert!(!deep_eq(
+            &one,
+            &row(vec![("name", string("nu")), ("version", string("0.8"))])
+        ));
+    }
+
+    #[test]
+    fn tables_are_only_deeply_equal_in_the_same_order() {
+        assert!(deep_eq(
+            &table(vec![int(1), int(2)]),
+            &table(vec![int(1), int(2)])
+        ));
+        assert!(!deep_eq(
+            &table(vec![int(1), int(2)]),
+            &table(vec![int(2), int(1)])
+        ));
+    }
+
+    #[test]
+    fn reads_ints_and_decimals_as_numbers() {
+        assert_eq!(as_number(&int(7)).unwrap(), Number::from(7));
+        assert_eq!(
+            as_number(&decimal("2.5")).unwrap(),
+            Number::Decimal("2.5".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn only_ints_and_decimals_are_numbers() {
+        let bytes = UntaggedValue::bytes(1024u64).into_untagged_value();
+
+        assert!(as_number(&string("7")).is_err());
+        assert!(as_number(&bytes).is_err());
+        assert!(as_number(&table(vec![int(7)])).is_err());
+    }
+
+    fn bytes(size: u64) -> Value {
+        UntaggedValue::bytes(size).into_untagged_value()
+    }
+
+    #[test]
+    fn formats_bytes_below_a_kilobyte_as_whole_bytes() {
+        assert_eq!(format_bytes(&bytes(0)), Some("0 B".to_string()));
+        assert_eq!(format_bytes(&bytes(999)), Some("999 B".to_string()));
+    }
+
+    #[test]
+    fn formats_bytes_around_1024_in_kilobytes() {
+        assert_eq!(format_bytes(&bytes(1023)), Some("1.0 KB".to_string()));
+        assert_eq!(format_bytes(&bytes(1024)), Some("1.0 KB".to_string()));
+    }
+
+    #[test]
+    fn formats_one_and_a_half_megabytes() {
+        assert_eq!(format_bytes(&bytes(1_500_000)), Some("1.5 MB".to_string()));
+    }
+
+    #[test]
+    fn does_not_format_other_values_as_bytes() {
+        assert_eq!(format_bytes(&int(1024)), None);
+    }
+}
@@ -404,8 +404,11 @@ pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
         UntaggedValue::Primitive(Primitive::Bytes(b)) => serde_json::Value::Number(
             serde_json::Number::from(b.to_u64().expect("What about really big numbers")),
         ),
-        UntaggedValue::Primitive(Primitive::Duration(secs)) => {
-            serde_json::Value::Number(serde_json::Number::from(*secs))
+        UntaggedValue::Primitive(Primitive::Duration(nanos)) => {
+            serde_json::Value::Number(serde_json::Number::from(CoerceInto::<i64>::coerce_into(
+                nanos.tagged(&v.tag),
+                "converting to JSON number",
+            )?))
         }
         UntaggedValue::Primitive(Primitive::Date(d)) => serde_json::Value::String(d.to_string()),
         UntaggedValue::Primitive(Primitive::EndOfStream) => serde_json::Value::Null,
@@ -438,6 +441,10 @@ pub fn value_to_json_value(v: &Value) -> Result<serde_json::Value, ShellError> {
                             "converting to JSON number",
                         )?),
                     )),
+                    UnspannedPathMember::Wildcard => Ok(serde_json::Value::String("*".to_string())),
+                    UnspannedPathMember::Range { from, to } => {
+                        Ok(serde_json::Value::String(format!("{}..{}", from, to)))
+                    }
                 })
                 .collect::<Result<Vec<serde_json::Value>, ShellError>>()?,
         ),
@@ -244,6 +244,8 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
             whole_stream_command(Save),
             per_item_command(Cpy),
             whole_stream_command(Date),
+            per_item_command(DateFormat),
+            per_item_command(DateDiff),
             per_item_command(Mkdir),
             per_item_command(Move),
             whole_stream_command(Version),
@@ -267,6 +269,7 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
             // Text manipulation
             whole_stream_command(SplitColumn),
             whole_stream_command(SplitRow),
+            whole_stream_command(StrJoin),
             whole_stream_command(Lines),
             whole_stream_command(Trim),
             per_item_command(Echo),
@@ -277,11 +280,14 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
             whole_stream_command(Get),
             per_item_command(Edit),
             per_item_command(Insert),
+            per_item_command(Rename),
+            whole_stream_command(Merge),
             whole_stream_command(SplitBy),
             // Row manipulation
             whole_stream_command(Reverse),
             whole_stream_command(Append),
             whole_stream_command(Prepend),
+            whole_stream_command(MergeStreams),
             whole_stream_command(SortBy),
             whole_stream_command(GroupBy),
             whole_stream_command(First),
@@ -293,13 +299,17 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
             whole_stream_command(Compact),
             whole_stream_command(Default),
             whole_stream_command(SkipWhile),
+            whole_stream_command(TakeWhile),
             whole_stream_command(Range),
             // Table manipulation
             whole_stream_command(Wrap),
             whole_stream_command(Pivot),
+            whole_stream_command(PivotWider),
+            whole_stream_command(PivotLonger),
             // Data processing
             whole_stream_command(Histogram),
             // File format output
+            whole_stream_command(ToArray),
             whole_stream_command(ToBSON),
             whole_stream_command(ToCSV),
             whole_stream_command(ToJSON),
@@ -339,9 +349,10 @@ pub async fn cli() -> Result<(), Box<dyn Error>> {
 
         #[cfg(feature = "clipboard")]
         {
-            context.add_commands(vec![whole_stream_command(
-                crate::commands::clip::clipboard::Clip,
-            )]);
+            context.add_commands(vec![
+                whole_stream_command(crate::commands::clip::clipboard::Clip),
+                whole_stream_command(crate::commands::paste::clipboard::Paste),
+            ]);
         }
     }
 
@@ -16,6 +16,8 @@ pub(crate) mod config;
 pub(crate) mod count;
 pub(crate) mod cp;
 pub(crate) mod date;
+pub(crate) mod date_diff;
+pub(crate) mod date_format;
 pub(crate) mod debug;
 pub(crate) mod default;
 pub(crate) mod echo;
@@ -50,14 +52,19 @@ pub(crate) mod lines;
 pub(crate) mod ls;
 #[allow(unused)]
 pub(crate) mod map_max_by;
+pub(crate) mod merge;
+pub(crate) mod merge_streams;
 pub(crate) mod mkdir;
 pub(crate) mod mv;
 pub(crate) mod next;
 pub(crate) mod nth;
 pub(crate) mod open;
 pub(crate) mod parse;
+pub(crate) mod paste;
 pub(crate) mod pick;
 pub(crate) mod pivot;
+pub(crate) mod pivot_longer;
+pub(crate) mod pivot_wider;
 pub(crate) mod plugin;
 pub(crate) mod prepend;
 pub(crate) mod prev;
@@ -66,6 +73,7 @@ pub(crate) mod range;
 #[allow(unused)]
 pub(crate) mod reduce_by;
 pub(crate) mod reject;
+pub(crate) mod rename;
 pub(crate) mod reverse;
 pub(crate) mod rm;
 pub(crate) mod save;
@@ -77,10 +85,13 @@ pub(crate) mod sort_by;
 pub(crate) mod split_by;
 pub(crate) mod split_column;
 pub(crate) mod split_row;
+pub(crate) mod str_join;
 #[allow(unused)]
 pub(crate) mod t_sort_by;
 pub(crate) mod table;
 pub(crate) mod tags;
+pub(crate) mod take_while;
+pub(crate) mod to_array;
 pub(crate) mod to_bson;
 pub(crate) mod to_csv;
 pub(crate) mod to_json;
@@ -109,6 +120,8 @@ pub(crate) use config::Config;
 pub(crate) use count::Count;
 pub(crate) use cp::Cpy;
 pub(crate) use date::Date;
+pub(crate) use date_diff::DateDiff;
+pub(crate) use date_format::DateFormat;
 pub(crate) use debug::Debug;
 pub(crate) use default::Default;
 pub(crate) use echo::Echo;
@@ -145,6 +158,8 @@ pub(crate) use lines::Lines;
 pub(crate) use ls::LS;
 #[allow(unused)]
 pub(crate) use map_max_by::MapMaxBy;
+pub(crate) use merge::Merge;
+pub(crate) use merge_streams::MergeStreams;
 pub(crate) use mkdir::Mkdir;
 pub(crate) use mv::Move;
 pub(crate) use next::Next;
@@ -153,6 +168,8 @@ pub(crate) use open::Open;
 pub(crate) use parse::Parse;
 pub(crate) use pick::Pick;
 pub(crate) use pivot::Pivot;
+pub(crate) use pivot_longer::PivotLonger;
+pub(crate) use pivot_wider::PivotWider;
 pub(crate) use prepend::Prepend;
 pub(crate) use prev::Previous;
 pub(crate) use pwd::PWD;
@@ -160,6 +177,7 @@ pub(crate) use range::Range;
 #[allow(unused)]
 pub(crate) use reduce_by::ReduceBy;
 pub(crate) use reject::Reject;
+pub(crate) use rename::Rename;
 pub(crate) use reverse::Reverse;
 pub(crate) use rm::Remove;
 pub(crate) use save::Save;
@@ -171,10 +189,13 @@ pub(crate) use sort_by::SortBy;
 pub(crate) use split_by::SplitBy;
 pub(crate) use split_column::SplitColumn;
 pub(crate) use split_row::SplitRow;
+pub(crate) use str_join::StrJoin;
 #[allow(unused)]
 pub(crate) use t_sort_by::TSortBy;
 pub(crate) use table::Table;
 pub(crate) use tags::Tags;
+pub(crate) use take_while::TakeWhile;
+pub(crate) use to_array::ToArray;
 pub(crate) use to_bson::ToBSON;
 pub(crate) use to_csv::ToCSV;
 pub(crate) use to_json::ToJSON;
@@ -196,9 +196,16 @@ pub(crate) async fn run_external_command(
                 let stdout = popen.stdout.take().unwrap();
                 let file = futures::io::AllowStdIo::new(stdout);
                 let stream = Framed::new(file, LinesCodec {});
-                let stream = stream.map(move |line| line.unwrap().into_value(&name_tag));
+                let stream = stream.map(move |line| match line {
+                    Ok(line) => Ok(line.into_value(&name_tag)),
+                    Err(err) => Err(ShellError::labeled_error(
+                        format!("Could not read the output of the command: {}", err),
+                        "command whose output could not be read",
+                        name_tag.span,
+                    )),
+                });
                 Ok(ClassifiedInputStream::from_input_stream(
-                    stream.boxed() as BoxStream<'static, Value>
+                    stream.to_input_stream(),
                 ))
             }
         }
@@ -3,7 +3,7 @@ use crate::prelude::*;
 use log::{log_enabled, trace};
 use nu_errors::ShellError;
 use nu_parser::InternalCommand;
-use nu_protocol::{CommandAction, Primitive, ReturnSuccess, UntaggedValue, Value};
+use nu_protocol::{CommandAction, LeaveShellScope, Primitive, ReturnSuccess, UntaggedValue, Value};
 
 use super::ClassifiedInputStream;
 
@@ -34,7 +34,7 @@ pub(crate) async fn run_internal_command(
         )
     };
 
-    let result = trace_out_stream!(target: "nu::trace_stream::internal", "output" = result);
+    let result = trace_stream!(target: "nu::trace_stream::internal", "output" = result);
     let mut result = result.values;
     let mut context = context.clone();
 
@@ -49,6 +49,7 @@ pub(crate) async fn run_internal_command(
                         context.shell_manager.set_path(path);
                     }
                     CommandAction::Exit => std::process::exit(0), // TODO: save history.txt
+                    CommandAction::ExitWithCode(code) => std::process::exit(code), // TODO: save history.txt
                     CommandAction::Error(err) => {
                         context.error(err);
                         break;
@@ -129,8 +130,12 @@ pub(crate) async fn run_internal_command(
                     CommandAction::NextShell => {
                         context.shell_manager.next();
                     }
-                    CommandAction::LeaveShell => {
-                        context.shell_manager.remove_at_current();
+                    CommandAction::LeaveShell(scope) => {
+                        match scope {
+                            LeaveShellScope::Current => context.shell_manager.remove_at_current(),
+                            LeaveShellScope::All => context.shell_manager.remove_all(),
+                        }
+
                         if context.shell_manager.is_empty() {
                             std::process::exit(0); // TODO: save history.txt
                         }
@@ -166,5 +171,5 @@ pub(crate) async fn run_internal_command(
         }
     };
 
-    Ok(stream.to_input_stream())
+    Ok(stream.to_input_stream_checked())
 }
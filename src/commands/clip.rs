@@ -5,14 +5,18 @@ pub mod clipboard {
     use crate::prelude::*;
     use futures::stream::StreamExt;
     use nu_errors::ShellError;
-    use nu_protocol::{ReturnValue, Signature, Value};
+    use nu_protocol::{ReturnValue, Signature, SyntaxShape, Value};
+    use nu_source::Tagged;
 
     use clipboard::{ClipboardContext, ClipboardProvider};
 
     pub struct Clip;
 
     #[derive(Deserialize)]
-    pub struct ClipArgs {}
+    pub struct ClipArgs {
+        append: bool,
+        separator: Option<Tagged<String>>,
+    }
 
     impl WholeStreamCommand for Clip {
         fn name(&self) -> &str {
@@ -21,6 +25,15 @@ pub mod clipboard {
 
         fn signature(&self) -> Signature {
             Signature::build("clip")
+                .switch(
+                    "append",
+                    "add to the current contents of the copy/paste buffer",
+                )
+                .named(
+                    "separator",
+                    SyntaxShape::String,
+                    "the text to put between values (defaults to a newline)",
+                )
         }
 
         fn usage(&self) -> &str {
@@ -37,13 +50,19 @@ pub mod clipboard {
     }
 
     pub fn clip(
-        ClipArgs {}: ClipArgs,
-        RunnableContext { input, name, .. }: RunnableContext,
+        ClipArgs { append, separator }: ClipArgs,
+        context: RunnableContext,
     ) -> Result<OutputStream, ShellError> {
+        let name = context.name.clone();
+        let separator = match separator {
+            Some(separator) => separator.item,
+            None => "\n".to_string(),
+        };
+
         let stream = async_stream! {
-            let values: Vec<Value> = input.values.collect().await;
+            let values = context.collect_values().await;
 
-            let mut clip_stream = inner_clip(values, name).await;
+            let mut clip_stream = inner_clip(values, append, separator, name).await;
             while let Some(value) = clip_stream.next().await {
                 yield value;
             }
@@ -54,36 +73,250 @@ pub mod clipboard {
         Ok(OutputStream::from(stream))
     }
 
-    async fn inner_clip(input: Vec<Value>, name: Tag) -> OutputStream {
-        let mut clip_context: ClipboardContext = ClipboardProvider::new().unwrap();
-        let mut new_copy_data = String::new();
-
-        if input.len() > 0 {
-            let mut first = true;
-            for i in input.iter() {
-                if !first {
-                    new_copy_data.push_str("\n");
-                } else {
-                    first = false;
+    async fn inner_clip(
+        input: Vec<Value>,
+        append: bool,
+        separator: String,
+        name: Tag,
+    ) -> OutputStream {
+        match copy_to_clipboard::<ClipboardContext>(&input, append, &separator, &name) {
+            Ok(()) => OutputStream::empty(),
+            Err(err) => OutputStream::one(Err(err)),
+        }
+    }
+
+    fn copy_to_clipboard<C: ClipboardProvider>(
+        input: &[Value],
+        append: bool,
+        separator: &str,
+        name: &Tag,
+    ) -> Result<(), ShellError> {
+        let mut clip_context = C::new().map_err(|_| {
+            ShellError::labeled_error(
+                "No clipboard available",
+                "could not open the copy/paste buffer",
+                name,
+            )
+        })?;
+
+        copy_to(&mut clip_context, input, append, separator, name)
+    }
+
+    fn copy_to(
+        clip_context: &mut impl ClipboardProvider,
+        input: &[Value],
+        append: bool,
+        separator: &str,
+        name: &Tag,
+    ) -> Result<(), ShellError> {
+        let mut pieces = vec![];
+
+        if append && input.len() > 0 {
+            // An empty or unreadable clipboard leaves nothing to append to
+            match clip_context.get_contents() {
+                Ok(existing) if !existing.is_empty() => pieces.push(existing),
+                _ => {}
+            }
+        }
+
+        for i in input.iter() {
+            match i.as_string() {
+                Ok(string) => pieces.push(string),
+                Err(_) => {
+                    return Err(ShellError::labeled_error(
+                        "Given non-string data",
+                        "expected strings from pipeline",
+                        name,
+                    ))
                 }
+            }
+        }
+
+        let new_copy_data = pieces.join(separator);
 
-                let string: String = match i.as_string() {
-                    Ok(string) => string.to_string(),
-                    Err(_) => {
-                        return OutputStream::one(Err(ShellError::labeled_error(
-                            "Given non-string data",
-                            "expected strings from pipeline",
-                            name,
-                        )))
-                    }
-                };
-
-                new_copy_data.push_str(&string);
+        clip_context.set_contents(new_copy_data).map_err(|_| {
+            ShellError::labeled_error(
+                "Could not copy to the clipboard",
+                "the copy/paste buffer is not available",
+                name,
+            )
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{copy_to, copy_to_clipboard};
+        use clipboard::ClipboardProvider;
+        use nu_protocol::{UntaggedValue, Value};
+        use nu_source::Tag;
+        use std::error::Error;
+
+        struct StubClipboard {
+            contents: Option<String>,
+        }
+
+        impl ClipboardProvider for StubClipboard {
+            fn new() -> Result<Self, Box<dyn Error>> {
+                Ok(StubClipboard { contents: None })
+            }
+
+            fn get_contents(&mut self) -> Result<String, Box<dyn Error>> {
+                self.contents
+                    .clone()
+                    .ok_or_else(|| "the clipboard holds no text".into())
+            }
+
+            fn set_contents(&mut self, contents: String) -> Result<(), Box<dyn Error>> {
+                self.contents = Some(contents);
+                Ok(())
             }
         }
 
-        clip_context.set_contents(new_copy_data).unwrap();
+        struct UnavailableClipboard;
+
+        impl ClipboardProvider for UnavailableClipboard {
+            fn new() -> Result<Self, Box<dyn Error>> {
+                Err("no display to open a clipboard on".into())
+            }
 
-        OutputStream::empty()
+            fn get_contents(&mut self) -> Result<String, Box<dyn Error>> {
+                Err("no display to read the clipboard from".into())
+            }
+
+            fn set_contents(&mut self, _: String) -> Result<(), Box<dyn Error>> {
+                Err("no display to write the clipboard to".into())
+            }
+        }
+
+        fn strings(input: &[&str]) -> Vec<Value> {
+            input
+                .iter()
+                .map(|s| UntaggedValue::string(*s).into_untagged_value())
+                .collect()
+        }
+
+        #[test]
+        fn appends_after_the_current_contents() {
+            let mut clipboard = StubClipboard {
+                contents: Some("andres".to_string()),
+            };
+
+            copy_to(
+                &mut clipboard,
+                &strings(&["yehuda", "jonathan"]),
+                true,
+                "\n",
+                &Tag::unknown(),
+            )
+            .unwrap();
+
+            assert_eq!(
+                clipboard.contents,
+                Some("andres\nyehuda\njonathan".to_string())
+            );
+        }
+
+        #[test]
+        fn appends_to_an_empty_clipboard_without_a_leading_newline() {
+            let mut clipboard = StubClipboard { contents: None };
+
+            copy_to(
+                &mut clipboard,
+                &strings(&["yehuda"]),
+                true,
+                "\n",
+                &Tag::unknown(),
+            )
+            .unwrap();
+
+            assert_eq!(clipboard.contents, Some("yehuda".to_string()));
+        }
+
+        #[test]
+        fn overwrites_without_append() {
+            let mut clipboard = StubClipboard {
+                contents: Some("andres".to_string()),
+            };
+
+            copy_to(
+                &mut clipboard,
+                &strings(&["yehuda"]),
+                false,
+                "\n",
+                &Tag::unknown(),
+            )
+            .unwrap();
+
+            assert_eq!(clipboard.contents, Some("yehuda".to_string()));
+        }
+
+        fn copy_with_separator(separator: &str) -> Option<String> {
+            let mut clipboard = StubClipboard { contents: None };
+
+            copy_to(
+                &mut clipboard,
+                &strings(&["yehuda", "jonathan", "andres"]),
+                false,
+                separator,
+                &Tag::unknown(),
+            )
+            .unwrap();
+
+            clipboard.contents
+        }
+
+        #[test]
+        fn joins_values_with_newlines_by_default() {
+            assert_eq!(
+                copy_with_separator("\n"),
+                Some("yehuda\njonathan\nandres".to_string())
+            );
+        }
+
+        #[test]
+        fn joins_values_with_an_empty_separator() {
+            assert_eq!(
+                copy_with_separator(""),
+                Some("yehudajonathanandres".to_string())
+            );
+        }
+
+        #[test]
+        fn joins_values_with_a_multi_character_separator() {
+            assert_eq!(
+                copy_with_separator(", "),
+                Some("yehuda, jonathan, andres".to_string())
+            );
+        }
+
+        #[test]
+        fn errors_when_no_clipboard_can_be_opened() {
+            let error = copy_to_clipboard::<UnavailableClipboard>(
+                &strings(&["yehuda"]),
+                false,
+                "\n",
+                &Tag::unknown(),
+            )
+            .unwrap_err();
+
+            assert_eq!(error.to_diagnostic().message, "No clipboard available");
+        }
+
+        #[test]
+        fn errors_when_the_clipboard_cannot_be_written() {
+            let error = copy_to(
+                &mut UnavailableClipboard,
+                &strings(&["yehuda"]),
+                true,
+                "\n",
+                &Tag::unknown(),
+            )
+            .unwrap_err();
+
+            assert_eq!(
+                error.to_diagnostic().message,
+                "Could not copy to the clipboard"
+            );
+        }
     }
 }
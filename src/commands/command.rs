@@ -230,6 +230,21 @@ impl RunnableContext {
     pub fn get_command(&self, name: &str) -> Option<Arc<Command>> {
         self.commands.get_command(name)
     }
+
+    /// Waits for the whole input and returns its values.
+    pub async fn collect_values(self) -> Vec<Value> {
+        self.input.into_vec().await
+    }
+
+    /// Waits for the whole input and returns each value as a string, as `as_string` reads it,
+    /// or the error for the first value that cannot be read as one.
+    pub async fn collect_strings(self) -> Result<Vec<String>, ShellError> {
+        self.collect_values()
+            .await
+            .iter()
+            .map(nu_value_ext::as_string)
+            .collect()
+    }
 }
 
 pub struct RunnablePerItemArgs<T> {
@@ -481,42 +496,10 @@ impl Command {
                 Ok(stream) => stream,
                 Err(err) => OutputStream::one(Err(err)),
             },
-            Command::PerItem(command) => self.run_helper(command.clone(), args, registry.clone()),
+            Command::PerItem(command) => run_per_item(command.clone(), args, registry.clone()),
         }
     }
 
-    fn run_helper(
-        &self,
-        command: Arc<dyn PerItemCommand>,
-        args: CommandArgs,
-        registry: CommandRegistry,
-    ) -> OutputStream {
-        let raw_args = RawCommandArgs {
-            host: args.host,
-            ctrl_c: args.ctrl_c,
-            shell_manager: args.shell_manager,
-            call_info: args.call_info,
-        };
-
-        let out = args
-            .input
-            .values
-            .map(move |x| {
-                let call_info = raw_args
-                    .clone()
-                    .call_info
-                    .evaluate(&registry, &Scope::it_value(x.clone()))
-                    .unwrap();
-                match command.run(&call_info, &registry, &raw_args, x) {
-                    Ok(o) => o,
-                    Err(e) => VecDeque::from(vec![ReturnValue::Err(e)]).to_output_stream(),
-                }
-            })
-            .flatten();
-
-        out.to_output_stream()
-    }
-
     pub fn is_binary(&self) -> bool {
         match self {
             Command::WholeStream(command) => command.is_binary(),
@@ -525,6 +508,85 @@ impl Command {
     }
 }
 
+/// Runs `command` once for every input value, with its arguments evaluated against that value
+/// as `$it`, and chains the outputs together.
+fn run_per_item(
+    command: Arc<dyn PerItemCommand>,
+    args: CommandArgs,
+    registry: CommandRegistry,
+) -> OutputStream {
+    let raw_args = RawCommandArgs {
+        host: args.host,
+        ctrl_c: args.ctrl_c,
+        shell_manager: args.shell_manager,
+        call_info: args.call_info,
+    };
+
+    let out = args
+        .input
+        .values
+        .map(move |x| {
+            let call_info = match raw_args
+                .clone()
+                .call_info
+                .evaluate(&registry, &Scope::it_value(x.clone()))
+            {
+                Ok(call_info) => call_info,
+                Err(e) => return VecDeque::from(vec![ReturnValue::Err(e)]).to_output_stream(),
+            };
+
+            match command.run(&call_info, &registry, &raw_args, x) {
+                Ok(o) => o,
+                Err(e) => VecDeque::from(vec![ReturnValue::Err(e)]).to_output_stream(),
+            }
+        })
+        .flatten();
+
+    out.to_output_stream()
+}
+
+/// Wraps a `PerItemCommand` so it can be used wherever a `WholeStreamCommand` is expected,
+/// running it over the input stream exactly as the shell runs per-item commands.
+#[allow(dead_code)]
+pub struct PerItemAdapter {
+    command: Arc<dyn PerItemCommand>,
+}
+
+#[allow(dead_code)]
+impl PerItemAdapter {
+    pub fn new(command: impl PerItemCommand + 'static) -> PerItemAdapter {
+        PerItemAdapter {
+            command: Arc::new(command),
+        }
+    }
+}
+
+impl WholeStreamCommand for PerItemAdapter {
+    fn name(&self) -> &str {
+        self.command.name()
+    }
+
+    fn signature(&self) -> Signature {
+        self.command.signature()
+    }
+
+    fn usage(&self) -> &str {
+        self.command.usage()
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        Ok(run_per_item(self.command.clone(), args, registry.clone()))
+    }
+
+    fn is_binary(&self) -> bool {
+        self.command.is_binary()
+    }
+}
+
 pub struct FnFilterCommand {
     name: String,
     func: fn(EvaluatedFilterCommandArgs) -> Result<OutputStream, ShellError>,
@@ -591,3 +653,98 @@ pub fn whole_stream_command(command: impl WholeStreamCommand + 'static) -> Arc<C
 pub fn per_item_command(command: impl PerItemCommand + 'static) -> Arc<Command> {
     Arc::new(Command::PerItem(Arc::new(command)))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CommandArgs, PerItemAdapter, RunnableContext, UnevaluatedCallInfo, WholeStreamCommand,
+    };
+    use crate::commands::Echo;
+    use crate::context::CommandRegistry;
+    use crate::env::host::BasicHost;
+    use crate::prelude::*;
+    use crate::shell::shell_manager::ShellManager;
+    use futures::executor::block_on;
+    use nu_parser::hir;
+    use nu_protocol::ReturnSuccess;
+    use nu_protocol::{UntaggedValue, Value};
+    use std::sync::atomic::AtomicBool;
+
+    fn context(input: InputStream) -> RunnableContext {
+        RunnableContext {
+            input,
+            shell_manager: ShellManager::basic(CommandRegistry::new()).unwrap(),
+            host: Arc::new(Mutex::new(Box::new(BasicHost))),
+            source: Text::from(""),
+            ctrl_c: Arc::new(AtomicBool::new(false)),
+            commands: CommandRegistry::new(),
+            name: Tag::unknown(),
+        }
+    }
+
+    fn string(input: &str) -> Value {
+        UntaggedValue::string(input).into_untagged_value()
+    }
+
+    fn int(i: i64) -> Value {
+        UntaggedValue::int(i).into_untagged_value()
+    }
+
+    #[test]
+    fn collects_the_input_values() {
+        let values = block_on(context(input_stream![string("nu"), int(7)]).collect_values());
+
+        assert_eq!(values, vec![string("nu"), int(7)]);
+    }
+
+    #[test]
+    fn collects_the_input_as_strings() {
+        let strings = block_on(context(input_stream![string("nu"), int(7)]).collect_strings());
+
+        assert_eq!(strings.unwrap(), vec!["nu".to_string(), "7".to_string()]);
+    }
+
+    #[test]
+    fn collecting_strings_fails_on_the_first_value_that_is_not_one() {
+        let table = UntaggedValue::table(&vec![int(1)]).into_untagged_value();
+        let strings = block_on(context(input_stream![string("nu"), table]).collect_strings());
+
+        assert!(strings.is_err());
+    }
+
+    #[test]
+    fn adapted_per_item_commands_run_over_the_whole_stream() {
+        let adapter = PerItemAdapter::new(Echo);
+        let args = CommandArgs {
+            host: Arc::new(Mutex::new(Box::new(BasicHost))),
+            ctrl_c: Arc::new(AtomicBool::new(false)),
+            shell_manager: ShellManager::basic(CommandRegistry::new()).unwrap(),
+            call_info: UnevaluatedCallInfo {
+                args: hir::Call::new(
+                    Box::new(hir::Expression::synthetic_string("echo")),
+                    Some(vec![hir::Expression::it_variable(
+                        Span::unknown(),
+                        Span::unknown(),
+                    )]),
+                    None,
+                    Span::unknown(),
+                ),
+                source: Text::from(""),
+                name_tag: Tag::unknown(),
+            },
+            input: input_stream![int(1), int(2)],
+        };
+
+        let output = adapter.run(args, &CommandRegistry::new()).unwrap();
+        let values: Vec<Value> = block_on(output.values.collect::<Vec<_>>())
+            .into_iter()
+            .map(|item| match item {
+                Ok(ReturnSuccess::Value(value)) => value,
+                _ => panic!("expected only values from echo"),
+            })
+            .collect();
+
+        assert_eq!(adapter.name(), "echo");
+        assert_eq!(values, vec![int(1), int(2)]);
+    }
+}
@@ -40,8 +40,9 @@ pub fn compact(
     RunnableContext { input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
     let objects = input.values.filter(move |item| {
+        // Nothing, empty strings, empty rows and empty tables are all dropped
         let keep = if columns.is_empty() {
-            item.is_some()
+            !item.is_empty()
         } else {
             match item {
                 Value {
@@ -49,7 +50,7 @@ pub fn compact(
                     ..
                 } => columns
                     .iter()
-                    .all(|field| r.get_data(field).borrow().is_some()),
+                    .all(|field| !r.get_data(field).borrow().is_empty()),
                 _ => false,
             }
         };
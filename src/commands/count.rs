@@ -1,9 +1,8 @@
 use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
-use futures::stream::StreamExt;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, UntaggedValue, Value};
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue};
 
 pub struct Count;
 
@@ -34,10 +33,12 @@ impl WholeStreamCommand for Count {
 
 pub fn count(
     CountArgs {}: CountArgs,
-    RunnableContext { input, name, .. }: RunnableContext,
+    context: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
+    let name = context.name.clone();
+
     let stream = async_stream! {
-        let rows: Vec<Value> = input.values.collect().await;
+        let rows = context.collect_values().await;
 
         yield ReturnSuccess::value(UntaggedValue::int(rows.len()).into_value(name))
     };
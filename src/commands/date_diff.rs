@@ -0,0 +1,81 @@
+use crate::commands::PerItemCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use chrono::{DateTime, Utc};
+use nu_errors::ShellError;
+use nu_protocol::{
+    CallInfo, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue,
+    Value, NANOS_PER_SEC,
+};
+use nu_value_ext::ValueExt;
+use num_bigint::BigInt;
+use num_traits::Signed;
+
+pub struct DateDiff;
+
+impl PerItemCommand for DateDiff {
+    fn name(&self) -> &str {
+        "date-diff"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date-diff")
+            .required(
+                "start",
+                SyntaxShape::ColumnPath,
+                "the column holding the starting date",
+            )
+            .optional(
+                "end",
+                SyntaxShape::ColumnPath,
+                "the column holding the ending date (defaults to now)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Compute the duration between two date columns, or between a date column and now."
+    }
+
+    fn run(
+        &self,
+        call_info: &CallInfo,
+        _registry: &CommandRegistry,
+        _raw_args: &RawCommandArgs,
+        value: Value,
+    ) -> Result<OutputStream, ShellError> {
+        let start = date_at(&value, call_info.args.expect_nth(0)?)?;
+        let end = match call_info.args.nth(1) {
+            Some(field) => date_at(&value, field)?,
+            None => Utc::now(),
+        };
+
+        // Whole seconds and the nanoseconds past them are taken apart, since the full
+        // difference in nanoseconds overflows an i64 for dates about 292 years apart
+        let difference = end.signed_duration_since(start);
+        let seconds = difference.num_seconds();
+        let subsec_nanos = (difference - chrono::Duration::seconds(seconds))
+            .num_nanoseconds()
+            .expect("less than a second of nanoseconds fits in an i64");
+
+        // Durations are unsigned, so the order of the two dates doesn't matter
+        let nanos = (BigInt::from(seconds) * NANOS_PER_SEC + subsec_nanos).abs();
+
+        Ok(VecDeque::from(vec![ReturnSuccess::value(
+            UntaggedValue::duration(nanos).into_value(&value.tag),
+        )])
+        .to_output_stream())
+    }
+}
+
+fn date_at(value: &Value, field: &Value) -> Result<DateTime<Utc>, ShellError> {
+    let field = field.as_column_path()?;
+    let date = value.get_data_by_column_path(&field, Box::new(|(_, _, err)| err))?;
+
+    match &date.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => Ok(*date),
+        other => Err(ShellError::type_error(
+            "date",
+            other.type_name().spanned(date.tag.span),
+        )),
+    }
+}
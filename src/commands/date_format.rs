@@ -0,0 +1,91 @@
+use crate::commands::PerItemCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    CallInfo, Primitive, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
+use nu_value_ext::ValueExt;
+use std::fmt::Write;
+
+pub struct DateFormat;
+
+impl PerItemCommand for DateFormat {
+    fn name(&self) -> &str {
+        "date-format"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("date-format")
+            .required(
+                "format",
+                SyntaxShape::String,
+                "the strftime-style pattern to output. Eg) \"%Y-%m-%d %H:%M\"",
+            )
+            .optional(
+                "column",
+                SyntaxShape::ColumnPath,
+                "the column holding the date to format",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Format a date into a string using a strftime-style pattern."
+    }
+
+    fn run(
+        &self,
+        call_info: &CallInfo,
+        _registry: &CommandRegistry,
+        _raw_args: &RawCommandArgs,
+        value: Value,
+    ) -> Result<OutputStream, ShellError> {
+        let pattern = call_info.args.expect_nth(0)?;
+        let format = pattern.as_string()?;
+
+        let output = match call_info.args.nth(1) {
+            Some(field) => {
+                let field = field.as_column_path()?;
+                let date = value.get_data_by_column_path(&field, Box::new(|(_, _, err)| err))?;
+                let formatted = format_date(&date, &format, &pattern.tag)?;
+
+                match value.replace_data_at_column_path(&field, formatted) {
+                    Some(v) => v,
+                    None => {
+                        return Err(ShellError::labeled_error(
+                            "date-format could not find column to replace",
+                            "column name",
+                            &field.tag,
+                        ))
+                    }
+                }
+            }
+            None => format_date(&value, &format, &pattern.tag)?,
+        };
+
+        Ok(VecDeque::from(vec![ReturnSuccess::value(output)]).to_output_stream())
+    }
+}
+
+fn format_date(value: &Value, format: &str, format_tag: &Tag) -> Result<Value, ShellError> {
+    match &value.value {
+        UntaggedValue::Primitive(Primitive::Date(date)) => {
+            let mut output = String::new();
+
+            // Unknown specifiers surface as a fmt::Error, which `to_string` would panic on
+            if write!(output, "{}", date.format(format)).is_err() {
+                return Err(ShellError::labeled_error(
+                    "Invalid date format",
+                    "could not format the date with this pattern",
+                    format_tag,
+                ));
+            }
+
+            Ok(UntaggedValue::string(output).into_value(&value.tag))
+        }
+        other => Err(ShellError::type_error(
+            "date",
+            other.type_name().spanned(value.tag.span),
+        )),
+    }
+}
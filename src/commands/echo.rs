@@ -1,6 +1,8 @@
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{CallInfo, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{
+    CallInfo, ReturnSuccess, ShellTypeName, Signature, SyntaxShape, UntaggedValue, Value,
+};
 
 pub struct Echo;
 
@@ -10,7 +12,24 @@ impl PerItemCommand for Echo {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("echo").rest(SyntaxShape::Any, "the values to echo")
+        Signature::build("echo")
+            .rest(SyntaxShape::Any, "the values to echo")
+            .switch_short(
+                "no-separator",
+                "join the values into a single string with nothing between them",
+                'n',
+            )
+            .named_short(
+                "separator",
+                SyntaxShape::String,
+                "join the values into a single string with this between them",
+                's',
+            )
+            .switch_short(
+                "raw",
+                "echo tables as a single value instead of row by row",
+                'r',
+            )
     }
 
     fn usage(&self) -> &str {
@@ -33,6 +52,21 @@ fn run(
     _registry: &CommandRegistry,
     _raw_args: &RawCommandArgs,
 ) -> Result<OutputStream, ShellError> {
+    let separator = match call_info.args.get("separator") {
+        Some(separator) => Some(separator.as_string()?),
+        None if call_info.args.has("no-separator") => Some(String::new()),
+        None => None,
+    };
+
+    if let Some(separator) = separator {
+        let joined = join_strings(call_info.args.positional_iter(), &separator)?;
+
+        return Ok(OutputStream::once(
+            UntaggedValue::string(joined).into_value(&call_info.name_tag),
+        ));
+    }
+
+    let raw = call_info.args.has("raw");
     let mut output = vec![];
 
     if let Some(ref positional) = call_info.args.positional {
@@ -47,7 +81,7 @@ fn run(
                     Value {
                         value: UntaggedValue::Table(table),
                         ..
-                    } => {
+                    } if !raw => {
                         for value in table {
                             output.push(Ok(ReturnSuccess::Value(value.clone())));
                         }
@@ -64,3 +98,29 @@ fn run(
 
     Ok(stream.to_output_stream())
 }
+
+fn join_strings<'a>(
+    values: impl Iterator<Item = &'a Value>,
+    separator: &str,
+) -> Result<String, ShellError> {
+    let mut strings = vec![];
+
+    for (idx, value) in values.enumerate() {
+        match value.as_string() {
+            Ok(string) => strings.push(string),
+            Err(_) => {
+                return Err(ShellError::labeled_error(
+                    "Expected a string",
+                    format!(
+                        "argument {} is {} and can't be joined into a string",
+                        idx + 1,
+                        value.type_name()
+                    ),
+                    &value.tag,
+                ))
+            }
+        }
+    }
+
+    Ok(strings.join(separator))
+}
@@ -38,7 +38,7 @@ impl PerItemCommand for Edit {
         value: Value,
     ) -> Result<OutputStream, ShellError> {
         let value_tag = value.tag();
-        let field = call_info.args.expect_nth(0)?.as_column_path().unwrap();
+        let field = call_info.args.expect_nth(0)?.as_column_path()?;
         let replacement = call_info.args.expect_nth(1)?.tagged_unknown();
 
         let stream = match value {
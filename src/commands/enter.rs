@@ -52,20 +52,20 @@ impl PerItemCommand for Enter {
                     let (_, command) = (spec[0], spec[1]);
 
                     if registry.has(command) {
-                        Ok(vec![Ok(ReturnSuccess::Action(CommandAction::EnterHelpShell(
+                        Ok(vec![ReturnSuccess::action(CommandAction::EnterHelpShell(
                             UntaggedValue::string(command).into_value(Tag::unknown()),
-                        )))]
+                        ))]
                         .into())
                     } else {
-                        Ok(vec![Ok(ReturnSuccess::Action(CommandAction::EnterHelpShell(
+                        Ok(vec![ReturnSuccess::action(CommandAction::EnterHelpShell(
                             UntaggedValue::nothing().into_value(Tag::unknown()),
-                        )))]
+                        ))]
                         .into())
                     }
                 } else if PathBuf::from(location).is_dir() {
-                    Ok(vec![Ok(ReturnSuccess::Action(CommandAction::EnterShell(
+                    Ok(vec![ReturnSuccess::action(CommandAction::EnterShell(
                         location_clone,
-                    )))]
+                    ))]
                     .into())
                 } else {
                     let stream = async_stream! {
@@ -117,38 +117,36 @@ impl PerItemCommand for Enter {
                                                     value,
                                                     ..
                                                 })) => {
-                                                    yield Ok(ReturnSuccess::Action(CommandAction::EnterValueShell(
+                                                    yield ReturnSuccess::action(CommandAction::EnterValueShell(
                                                         Value {
                                                             value,
                                                             tag: contents_tag.clone(),
-                                                        })));
+                                                        }));
                                                 }
                                                 x => yield x,
                                             }
                                         }
                                     } else {
-                                        yield Ok(ReturnSuccess::Action(CommandAction::EnterValueShell(tagged_contents)));
+                                        yield ReturnSuccess::action(CommandAction::EnterValueShell(tagged_contents));
                                     }
                                 } else {
-                                    yield Ok(ReturnSuccess::Action(CommandAction::EnterValueShell(tagged_contents)));
+                                    yield ReturnSuccess::action(CommandAction::EnterValueShell(tagged_contents));
                                 }
                             }
                             _ => {
                                 let tagged_contents = contents.into_value(contents_tag);
 
-                                yield Ok(ReturnSuccess::Action(CommandAction::EnterValueShell(tagged_contents)));
+                                yield ReturnSuccess::action(CommandAction::EnterValueShell(tagged_contents));
                             }
                         }
                     };
                     Ok(stream.to_output_stream())
                 }
             }
-            x => Ok(
-                vec![Ok(ReturnSuccess::Action(CommandAction::EnterValueShell(
-                    x.clone(),
-                )))]
-                .into(),
-            ),
+            x => Ok(vec![ReturnSuccess::action(CommandAction::EnterValueShell(
+                x.clone(),
+            ))]
+            .into()),
         }
     }
 }
@@ -1,18 +1,32 @@
 use crate::commands::command::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
-use nu_errors::ShellError;
-use nu_protocol::{CommandAction, ReturnSuccess, Signature};
+use nu_errors::{ExpectedRange, ShellError};
+use nu_protocol::{CommandAction, LeaveShellScope, ReturnSuccess, Signature, SyntaxShape};
+use nu_source::{SpannedItem, Tagged};
+use std::convert::TryFrom;
 
 pub struct Exit;
 
+#[derive(Deserialize)]
+pub struct ExitArgs {
+    code: Option<Tagged<i64>>,
+    now: bool,
+}
+
 impl WholeStreamCommand for Exit {
     fn name(&self) -> &str {
         "exit"
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("exit").switch("now", "exit out of the shell immediately")
+        Signature::build("exit")
+            .optional(
+                "code",
+                SyntaxShape::Int,
+                "the exit status to leave the shell with",
+            )
+            .switch_short("now", "exit out of the shell immediately", 'n')
     }
 
     fn usage(&self) -> &str {
@@ -24,16 +38,83 @@ impl WholeStreamCommand for Exit {
         args: CommandArgs,
         registry: &CommandRegistry,
     ) -> Result<OutputStream, ShellError> {
-        exit(args, registry)
+        args.process(registry, exit)?.run()
+    }
+}
+
+pub fn exit(
+    ExitArgs { code, now }: ExitArgs,
+    _context: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    Ok(vec![ReturnSuccess::action(exit_action(code, now)?)].into())
+}
+
+fn exit_action(code: Option<Tagged<i64>>, now: bool) -> Result<CommandAction, ShellError> {
+    match code {
+        Some(code) => {
+            let status = i32::try_from(code.item).map_err(|_| {
+                ShellError::range_error(
+                    ExpectedRange::I32,
+                    &code.item.spanned(code.tag.span),
+                    "exiting with a status code",
+                )
+            })?;
+
+            Ok(CommandAction::ExitWithCode(status))
+        }
+        None if now => Ok(CommandAction::LeaveShell(LeaveShellScope::All)),
+        None => Ok(CommandAction::LeaveShell(LeaveShellScope::Current)),
     }
 }
 
-pub fn exit(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    let args = args.evaluate_once(registry)?;
+#[cfg(test)]
+mod tests {
+    use super::exit_action;
+    use nu_protocol::{CommandAction, LeaveShellScope};
+    use nu_source::TaggedItem;
+
+    #[test]
+    fn carries_the_exit_code() {
+        let action = exit_action(Some(2.tagged_unknown()), false).unwrap();
+
+        match action {
+            CommandAction::ExitWithCode(code) => assert_eq!(code, 2),
+            other => panic!("expected an exit with a code, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn leaves_the_shell_without_a_code() {
+        let action = exit_action(None, false).unwrap();
+
+        match action {
+            CommandAction::LeaveShell(LeaveShellScope::Current) => {}
+            other => panic!("expected to leave the current shell, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn leaves_every_shell_now_without_a_code() {
+        let action = exit_action(None, true).unwrap();
+
+        match action {
+            CommandAction::LeaveShell(LeaveShellScope::All) => {}
+            other => panic!("expected to leave all shells, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn exits_with_a_code_even_when_leaving_now() {
+        let action = exit_action(Some(2.tagged_unknown()), true).unwrap();
+
+        match action {
+            CommandAction::ExitWithCode(code) => assert_eq!(code, 2),
+            other => panic!("expected an exit with a code, got {:?}", other),
+        }
+    }
 
-    if args.call_info.args.has("now") {
-        Ok(vec![Ok(ReturnSuccess::Action(CommandAction::Exit))].into())
-    } else {
-        Ok(vec![Ok(ReturnSuccess::Action(CommandAction::LeaveShell))].into())
+    #[test]
+    fn rejects_codes_too_big_for_an_exit_status() {
+        assert!(exit_action(Some(i64::max_value().tagged_unknown()), false).is_err());
     }
 }
@@ -8,7 +8,7 @@ use nu_protocol::{
     did_you_mean, ColumnPath, ReturnSuccess, ReturnValue, Signature, SyntaxShape, UntaggedValue,
     Value,
 };
-use nu_source::{span_for_spanned_list, PrettyDebug};
+use nu_source::PrettyDebug;
 use nu_value_ext::get_data_by_column_path;
 
 pub struct Get;
@@ -50,6 +50,11 @@ pub fn get_column_path(path: &ColumnPath, obj: &Value) -> Result<Value, ShellErr
         obj,
         path,
         Box::new(move |(obj_source, column_path_tried, error)| {
+            // A range already says what's wrong with it
+            if column_path_tried.is_range() {
+                return error;
+            }
+
             if let UntaggedValue::Table(rows) = &obj_source.value {
                 let total = rows.len();
                 let end_tag = match fields
@@ -81,11 +86,12 @@ pub fn get_column_path(path: &ColumnPath, obj: &Value) -> Result<Value, ShellErr
                 );
             }
 
+            // Point at the member that failed, not the whole path
             if let Some(suggestions) = did_you_mean(&obj_source, column_path_tried) {
                 return ShellError::labeled_error(
                     "Unknown column",
                     format!("did you mean '{}'?", suggestions[0].1),
-                    span_for_spanned_list(fields.members().iter().map(|p| p.span)),
+                    column_path_tried.span,
                 );
             }
 
@@ -162,3 +168,31 @@ pub fn get(
         Ok(stream.to_output_stream())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::get_column_path;
+    use nu_protocol::{ColumnPath, PathMember, TaggedDictBuilder, UntaggedValue};
+    use nu_source::{Span, Tag};
+
+    #[test]
+    fn unknown_columns_point_at_the_failing_member() {
+        let mut inner = TaggedDictBuilder::new(Tag::unknown());
+        inner.insert_untagged("taconushell", UntaggedValue::int(1));
+
+        let mut row = TaggedDictBuilder::new(Tag::unknown());
+        row.insert_value("nu", inner.into_value());
+
+        let path = ColumnPath::new(vec![
+            PathMember::string("nu", Span::new(4, 6)),
+            PathMember::string("taconushel", Span::new(7, 17)),
+        ]);
+
+        let diagnostic = get_column_path(&path, &row.into_value())
+            .unwrap_err()
+            .to_diagnostic();
+
+        assert_eq!(diagnostic.message, "Unknown column");
+        assert_eq!(diagnostic.labels[0].span, Span::new(7, 17));
+    }
+}
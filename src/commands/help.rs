@@ -117,10 +117,21 @@ impl PerItemCommand for Help {
                     if !signature.named.is_empty() {
                         long_desc.push_str("\nflags:\n");
                         for (flag, ty) in signature.named {
+                            let short = signature
+                                .short_named
+                                .iter()
+                                .find(|(_, long)| **long == flag)
+                                .map(|(short, _)| short);
+
+                            let flag = match short {
+                                Some(short) => format!("-{}, --{}", short, flag),
+                                None => format!("--{}", flag),
+                            };
+
                             match ty.0 {
                                 NamedType::Switch => {
                                     long_desc.push_str(&format!(
-                                        "  --{}{} {}\n",
+                                        "  {}{} {}\n",
                                         flag,
                                         if !ty.1.is_empty() { ":" } else { "" },
                                         ty.1
@@ -128,7 +139,7 @@ impl PerItemCommand for Help {
                                 }
                                 NamedType::Mandatory(m) => {
                                     long_desc.push_str(&format!(
-                                        "  --{} <{}> (required parameter){} {}\n",
+                                        "  {} <{}> (required parameter){} {}\n",
                                         flag,
                                         m.display(),
                                         if !ty.1.is_empty() { ":" } else { "" },
@@ -137,7 +148,7 @@ impl PerItemCommand for Help {
                                 }
                                 NamedType::Optional(o) => {
                                     long_desc.push_str(&format!(
-                                        "  --{} <{}>{} {}\n",
+                                        "  {} <{}>{} {}\n",
                                         flag,
                                         o.display(),
                                         if !ty.1.is_empty() { ":" } else { "" },
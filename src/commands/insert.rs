@@ -2,7 +2,7 @@ use crate::commands::PerItemCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{CallInfo, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{CallInfo, Signature, SyntaxShape, UntaggedValue, Value};
 use nu_value_ext::ValueExt;
 
 pub struct Insert;
@@ -27,7 +27,7 @@ impl PerItemCommand for Insert {
     }
 
     fn usage(&self) -> &str {
-        "Edit an existing column to have a new value."
+        "Insert a new column, creating any missing rows along its path."
     }
 
     fn run(
@@ -38,27 +38,25 @@ impl PerItemCommand for Insert {
         value: Value,
     ) -> Result<OutputStream, ShellError> {
         let value_tag = value.tag();
-        let field = call_info.args.expect_nth(0)?.as_column_path().unwrap();
-        let replacement = call_info.args.expect_nth(1)?.tagged_unknown();
+        let field = call_info.args.expect_nth(0)?.as_column_path()?;
+        let replacement = call_info.args.expect_nth(1)?;
 
-        let stream = match value {
+        match value {
             obj @ Value {
                 value: UntaggedValue::Row(_),
                 ..
-            } => match obj.insert_data_at_column_path(&field, replacement.item.clone()) {
-                Ok(v) => VecDeque::from(vec![Ok(ReturnSuccess::Value(v))]),
-                Err(err) => return Err(err),
-            },
+            } => {
+                let inserted =
+                    obj.insert_data_at_column_path_creating(&field, replacement.clone())?;
 
-            _ => {
-                return Err(ShellError::labeled_error(
-                    "Unrecognized type in stream",
-                    "original value",
-                    value_tag,
-                ))
+                Ok(OutputStream::once(inserted))
             }
-        };
 
-        Ok(stream.to_output_stream())
+            _ => Err(ShellError::labeled_error(
+                "Unrecognized type in stream",
+                "original value",
+                value_tag,
+            )),
+        }
     }
 }
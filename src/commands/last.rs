@@ -4,6 +4,7 @@ use crate::prelude::*;
 use nu_errors::ShellError;
 use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
 use nu_source::Tagged;
+use std::collections::VecDeque;
 
 pub struct Last;
 
@@ -39,23 +40,31 @@ impl WholeStreamCommand for Last {
 }
 
 fn last(LastArgs { rows }: LastArgs, context: RunnableContext) -> Result<OutputStream, ShellError> {
+    let rows_desired = rows.map(|quantity| *quantity as usize).unwrap_or(1);
+
     let stream = async_stream! {
-        let v: Vec<_> = context.input.into_vec().await;
-
-        let rows_desired = if let Some(quantity) = rows {
-            *quantity
-        } else {
-         1
-        };
-
-        let count = (rows_desired as usize);
-        if count < v.len() {
-            let k = v.len() - count;
-            for x in v[k..].iter() {
-                let y: Value = x.clone();
-                yield ReturnSuccess::value(y)
+        let mut input = context.input.values;
+
+        // Only the rows that may still end up last are held on to. The buffer grows as rows
+        // arrive, since the amount asked for can be far larger than the input.
+        let mut buffer: VecDeque<Value> = VecDeque::new();
+
+        while let Some(item) = input.next().await {
+            if rows_desired == 0 {
+                continue;
+            }
+
+            if buffer.len() == rows_desired {
+                buffer.pop_front();
             }
+
+            buffer.push_back(item);
+        }
+
+        for item in buffer {
+            yield ReturnSuccess::value(item);
         }
     };
+
     Ok(stream.to_output_stream())
 }
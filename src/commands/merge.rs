@@ -0,0 +1,74 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Scope, Signature, SyntaxShape, UntaggedValue, Value};
+
+#[derive(Deserialize)]
+struct MergeArgs {
+    block: Evaluate,
+}
+
+pub struct Merge;
+
+impl WholeStreamCommand for Merge {
+    fn name(&self) -> &str {
+        "merge"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("merge").required(
+            "block",
+            SyntaxShape::Block,
+            "the block producing the table (or row) to merge in, with $it as the input table",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Merge the table (or row) produced by the block into the input, row by row"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, merge)?.run()
+    }
+}
+
+fn merge(
+    MergeArgs { block }: MergeArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+        let table = UntaggedValue::Table(rows.clone()).into_value(&name);
+
+        let others = match block.invoke(&Scope::new(table)) {
+            Ok(Value {
+                value: UntaggedValue::Table(others),
+                ..
+            }) => others,
+            Ok(other) => vec![other],
+            Err(err) => {
+                yield Err(err);
+                return;
+            }
+        };
+
+        // The input decides the shape of the output: the n-th input row is merged with the
+        // n-th row from the block. Input rows past the end of the block's output pass through
+        // unchanged, and block rows past the end of the input are dropped.
+        let mut others = others.into_iter();
+
+        for row in rows {
+            match others.next() {
+                Some(other) => yield row.merge(&other).map(ReturnSuccess::Value),
+                None => yield ReturnSuccess::value(row),
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
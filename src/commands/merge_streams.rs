@@ -0,0 +1,66 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    Evaluate, ReturnSuccess, ReturnValue, Scope, Signature, SyntaxShape, UntaggedValue, Value,
+};
+
+#[derive(Deserialize)]
+struct MergeStreamsArgs {
+    rest: Vec<Evaluate>,
+}
+
+pub struct MergeStreams;
+
+impl WholeStreamCommand for MergeStreams {
+    fn name(&self) -> &str {
+        "merge-streams"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("merge-streams").rest(
+            SyntaxShape::Block,
+            "the blocks whose output is appended to the stream, in order",
+        )
+    }
+
+    fn usage(&self) -> &str {
+        "Append the output of each block to the stream, one after the other"
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, merge_streams)?.run()
+    }
+}
+
+fn merge_streams(
+    MergeStreamsArgs { rest: blocks }: MergeStreamsArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    // Blocks are only evaluated once every stream before them has been drained
+    let merged = futures::stream::iter(blocks)
+        .map(|block| {
+            let output: VecDeque<ReturnValue> = match block.invoke(&Scope::empty()) {
+                Ok(Value {
+                    value: UntaggedValue::Table(rows),
+                    ..
+                }) => rows.into_iter().map(ReturnSuccess::value).collect(),
+                Ok(value) => VecDeque::from(vec![ReturnSuccess::value(value)]),
+                Err(err) => VecDeque::from(vec![Err(err)]),
+            };
+
+            futures::stream::iter(output)
+        })
+        .flatten();
+
+    Ok(input
+        .values
+        .map(ReturnSuccess::value)
+        .chain(merged)
+        .to_output_stream())
+}
@@ -28,5 +28,5 @@ impl WholeStreamCommand for Next {
 }
 
 fn next(_args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    Ok(vec![Ok(ReturnSuccess::Action(CommandAction::NextShell))].into())
+    Ok(vec![ReturnSuccess::action(CommandAction::NextShell)].into())
 }
@@ -2,13 +2,14 @@ use crate::commands::WholeStreamCommand;
 use crate::context::CommandRegistry;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{ReturnSuccess, Signature, SyntaxShape};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, Value};
 use nu_source::Tagged;
 
 #[derive(Deserialize)]
 struct NthArgs {
-    row_number: Tagged<u64>,
-    rest: Vec<Tagged<u64>>,
+    row_number: Tagged<i64>,
+    rest: Vec<Tagged<i64>>,
+    invert: bool,
 }
 
 pub struct Nth;
@@ -22,10 +23,11 @@ impl WholeStreamCommand for Nth {
         Signature::build("nth")
             .required(
                 "row number",
-                SyntaxShape::Any,
+                SyntaxShape::Int,
                 "the number of the row to return",
             )
-            .rest(SyntaxShape::Any, "Optionally return more rows")
+            .rest(SyntaxShape::Int, "Optionally return more rows")
+            .switch("invert", "return every row except the selected ones")
     }
 
     fn usage(&self) -> &str {
@@ -45,32 +47,72 @@ fn nth(
     NthArgs {
         row_number,
         rest: and_rows,
+        invert,
     }: NthArgs,
     RunnableContext { input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    let stream = input
-        .values
-        .enumerate()
-        .map(move |(idx, item)| {
-            let row_number = vec![row_number.clone()];
-
-            let row_numbers = vec![&row_number, &and_rows]
-                .into_iter()
-                .flatten()
-                .collect::<Vec<&Tagged<u64>>>();
-
-            let mut result = VecDeque::new();
-
-            if row_numbers
-                .iter()
-                .any(|requested| requested.item == idx as u64)
-            {
-                result.push_back(ReturnSuccess::value(item.clone()));
-            }
+    let row_numbers: Vec<i64> = std::iter::once(row_number)
+        .chain(and_rows)
+        .map(|row_number| row_number.item)
+        .collect();
+
+    let has_negatives = row_numbers.iter().any(|requested| *requested < 0);
+
+    // Without negative indices, rows to skip can be dropped as they pass by
+    if invert && !has_negatives {
+        let stream = input.values.enumerate().filter_map(move |(idx, item)| {
+            futures::future::ready(if row_numbers.contains(&(idx as i64)) {
+                None
+            } else {
+                Some(ReturnSuccess::value(item))
+            })
+        });
+
+        return Ok(stream.to_output_stream());
+    }
+
+    // Rows requested in the order they arrive can be picked as they pass by
+    if !invert && !has_negatives && row_numbers.windows(2).all(|pair| pair[0] <= pair[1]) {
+        let stream = input
+            .values
+            .enumerate()
+            .map(move |(idx, item)| {
+                row_numbers
+                    .iter()
+                    .filter(|requested| **requested == idx as i64)
+                    .map(|_| ReturnSuccess::value(item.clone()))
+                    .collect::<VecDeque<_>>()
+            })
+            .flatten();
 
-            result
-        })
-        .flatten();
+        return Ok(stream.to_output_stream());
+    }
+
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        // Negative indices count back from the last row
+        let indices: Vec<usize> = row_numbers
+            .iter()
+            .map(|requested| if *requested < 0 { rows.len() as i64 + requested } else { *requested })
+            .filter(|index| *index >= 0)
+            .map(|index| index as usize)
+            .collect();
+
+        if invert {
+            for (idx, row) in rows.iter().enumerate() {
+                if !indices.contains(&idx) {
+                    yield ReturnSuccess::value(row.clone());
+                }
+            }
+        } else {
+            for index in indices {
+                if let Some(row) = rows.get(index) {
+                    yield ReturnSuccess::value(row.clone());
+                }
+            }
+        }
+    };
 
     Ok(stream.to_output_stream())
 }
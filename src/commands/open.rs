@@ -77,7 +77,7 @@ fn run(call_info: &CallInfo, raw_args: &RawCommandArgs) -> Result<OutputStream,
         let tagged_contents = contents.into_value(&contents_tag);
 
         if let Some(extension) = file_extension {
-            yield Ok(ReturnSuccess::Action(CommandAction::AutoConvert(tagged_contents, extension)))
+            yield ReturnSuccess::action(CommandAction::AutoConvert(tagged_contents, extension))
         } else {
             yield ReturnSuccess::value(tagged_contents);
         }
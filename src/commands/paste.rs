@@ -0,0 +1,182 @@
+#[cfg(feature = "clipboard")]
+pub mod clipboard {
+    use crate::commands::WholeStreamCommand;
+    use crate::context::CommandRegistry;
+    use crate::prelude::*;
+    use nu_errors::ShellError;
+    use nu_protocol::{Signature, UntaggedValue, Value};
+
+    use clipboard::{ClipboardContext, ClipboardProvider};
+
+    pub struct Paste;
+
+    #[derive(Deserialize)]
+    pub struct PasteArgs {
+        lines: bool,
+    }
+
+    impl WholeStreamCommand for Paste {
+        fn name(&self) -> &str {
+            "paste"
+        }
+
+        fn signature(&self) -> Signature {
+            Signature::build("paste").switch("lines", "split the contents into rows, one per line")
+        }
+
+        fn usage(&self) -> &str {
+            "Paste the contents of the copy/paste buffer into the pipeline"
+        }
+
+        fn run(
+            &self,
+            args: CommandArgs,
+            registry: &CommandRegistry,
+        ) -> Result<OutputStream, ShellError> {
+            args.process(registry, paste)?.run()
+        }
+    }
+
+    pub fn paste(
+        PasteArgs { lines }: PasteArgs,
+        RunnableContext { name, .. }: RunnableContext,
+    ) -> Result<OutputStream, ShellError> {
+        let contents = paste_from_clipboard::<ClipboardContext>(&name)?;
+
+        Ok(pasted_values(contents, lines, &name).into())
+    }
+
+    fn paste_from_clipboard<C: ClipboardProvider>(name: &Tag) -> Result<String, ShellError> {
+        let mut clip_context = C::new().map_err(|_| {
+            ShellError::labeled_error(
+                "No clipboard available",
+                "could not open the copy/paste buffer",
+                name,
+            )
+        })?;
+
+        let contents = clip_context.get_contents().map_err(|_| {
+            ShellError::labeled_error(
+                "Could not paste from the clipboard",
+                "the copy/paste buffer holds no text",
+                name,
+            )
+        })?;
+
+        if contents.is_empty() {
+            return Err(ShellError::labeled_error(
+                "Nothing to paste",
+                "the copy/paste buffer is empty",
+                name,
+            ));
+        }
+
+        Ok(contents)
+    }
+
+    fn pasted_values(contents: String, lines: bool, name: &Tag) -> Vec<Value> {
+        if lines {
+            contents
+                .lines()
+                .map(|line| UntaggedValue::string(line).into_value(name))
+                .collect()
+        } else {
+            vec![UntaggedValue::string(contents).into_value(name)]
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{paste_from_clipboard, pasted_values};
+        use clipboard::ClipboardProvider;
+        use nu_protocol::{UntaggedValue, Value};
+        use nu_source::Tag;
+        use std::error::Error;
+
+        struct FilledClipboard;
+
+        impl ClipboardProvider for FilledClipboard {
+            fn new() -> Result<Self, Box<dyn Error>> {
+                Ok(FilledClipboard)
+            }
+
+            fn get_contents(&mut self) -> Result<String, Box<dyn Error>> {
+                Ok("yehuda\njonathan\nandres".to_string())
+            }
+
+            fn set_contents(&mut self, _: String) -> Result<(), Box<dyn Error>> {
+                Ok(())
+            }
+        }
+
+        struct EmptyClipboard;
+
+        impl ClipboardProvider for EmptyClipboard {
+            fn new() -> Result<Self, Box<dyn Error>> {
+                Ok(EmptyClipboard)
+            }
+
+            fn get_contents(&mut self) -> Result<String, Box<dyn Error>> {
+                Ok(String::new())
+            }
+
+            fn set_contents(&mut self, _: String) -> Result<(), Box<dyn Error>> {
+                Ok(())
+            }
+        }
+
+        struct UnavailableClipboard;
+
+        impl ClipboardProvider for UnavailableClipboard {
+            fn new() -> Result<Self, Box<dyn Error>> {
+                Err("no display to open a clipboard on".into())
+            }
+
+            fn get_contents(&mut self) -> Result<String, Box<dyn Error>> {
+                Err("no display to read the clipboard from".into())
+            }
+
+            fn set_contents(&mut self, _: String) -> Result<(), Box<dyn Error>> {
+                Err("no display to write the clipboard to".into())
+            }
+        }
+
+        fn string(input: &str) -> Value {
+            UntaggedValue::string(input).into_untagged_value()
+        }
+
+        #[test]
+        fn pastes_the_contents_as_a_single_string() {
+            let contents = paste_from_clipboard::<FilledClipboard>(&Tag::unknown()).unwrap();
+
+            assert_eq!(
+                pasted_values(contents, false, &Tag::unknown()),
+                vec![string("yehuda\njonathan\nandres")]
+            );
+        }
+
+        #[test]
+        fn pastes_the_contents_one_row_per_line() {
+            let contents = paste_from_clipboard::<FilledClipboard>(&Tag::unknown()).unwrap();
+
+            assert_eq!(
+                pasted_values(contents, true, &Tag::unknown()),
+                vec![string("yehuda"), string("jonathan"), string("andres")]
+            );
+        }
+
+        #[test]
+        fn errors_when_the_clipboard_is_empty() {
+            let error = paste_from_clipboard::<EmptyClipboard>(&Tag::unknown()).unwrap_err();
+
+            assert_eq!(error.to_diagnostic().message, "Nothing to paste");
+        }
+
+        #[test]
+        fn errors_when_no_clipboard_can_be_opened() {
+            let error = paste_from_clipboard::<UnavailableClipboard>(&Tag::unknown()).unwrap_err();
+
+            assert_eq!(error.to_diagnostic().message, "No clipboard available");
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue};
+use nu_source::Tagged;
+
+pub struct PivotLonger;
+
+#[derive(Deserialize)]
+pub struct PivotLongerArgs {
+    rest: Vec<Tagged<String>>,
+    key: Option<Tagged<String>>,
+    value: Option<Tagged<String>>,
+}
+
+impl WholeStreamCommand for PivotLonger {
+    fn name(&self) -> &str {
+        "pivot-longer"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pivot-longer")
+            .named(
+                "key",
+                SyntaxShape::String,
+                "the name of the column holding the gathered column names (defaults to key)",
+            )
+            .named(
+                "value",
+                SyntaxShape::String,
+                "the name of the column holding the gathered values (defaults to value)",
+            )
+            .rest(SyntaxShape::Member, "the columns to gather into rows")
+    }
+
+    fn usage(&self) -> &str {
+        "Gathers columns into key and value rows, repeating the other columns."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, pivot_longer)?.run()
+    }
+}
+
+pub fn pivot_longer(
+    PivotLongerArgs { rest, key, value }: PivotLongerArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let columns: Vec<String> = rest.into_iter().map(|column| column.item).collect();
+    let key = key.map(|key| key.item).unwrap_or_else(|| "key".to_string());
+    let value = value
+        .map(|value| value.item)
+        .unwrap_or_else(|| "value".to_string());
+
+    Ok(input
+        .values
+        .map(move |row| {
+            let dict = match &row.value {
+                UntaggedValue::Row(dict) => dict,
+                _ => {
+                    let err = ShellError::labeled_error_with_secondary(
+                        "Expected a table from pipeline",
+                        "requires table input",
+                        &name,
+                        "value originates from here",
+                        &row.tag,
+                    );
+                    return VecDeque::from(vec![Err(err)]);
+                }
+            };
+
+            let (gathered, kept): (Vec<_>, Vec<_>) = dict
+                .entries
+                .iter()
+                .partition(|(desc, _)| columns.contains(desc));
+
+            gathered
+                .into_iter()
+                .map(|(desc, cell)| {
+                    let mut long = TaggedDictBuilder::new(&row.tag);
+
+                    for &(kept_desc, kept_cell) in kept.iter() {
+                        long.insert_value(kept_desc.clone(), kept_cell.clone());
+                    }
+
+                    long.insert_value(
+                        key.clone(),
+                        UntaggedValue::string(desc).into_value(&row.tag),
+                    );
+                    long.insert_value(value.clone(), cell.clone());
+
+                    ReturnSuccess::value(long.into_value())
+                })
+                .collect::<VecDeque<_>>()
+        })
+        .flatten()
+        .to_output_stream())
+}
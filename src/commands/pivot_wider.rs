@@ -0,0 +1,152 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use indexmap::IndexMap;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value};
+use nu_source::{SpannedItem, Tagged};
+use nu_value_ext::get_data_by_key;
+
+pub struct PivotWider;
+
+#[derive(Deserialize)]
+pub struct PivotWiderArgs {
+    key: Tagged<String>,
+    value: Tagged<String>,
+    collect: bool,
+}
+
+impl WholeStreamCommand for PivotWider {
+    fn name(&self) -> &str {
+        "pivot-wider"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("pivot-wider")
+            .required(
+                "key",
+                SyntaxShape::Member,
+                "the column holding the names of the new columns",
+            )
+            .required(
+                "value",
+                SyntaxShape::Member,
+                "the column holding the values of the new columns",
+            )
+            .switch(
+                "collect",
+                "collect repeated keys into a table instead of keeping the last value",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Spreads key and value columns into one column per distinct key."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, pivot_wider)?.run()
+    }
+}
+
+struct WideRow {
+    tag: Tag,
+    identity: Vec<(String, Value)>,
+    spread: IndexMap<String, Vec<Value>>,
+}
+
+pub fn pivot_wider(
+    PivotWiderArgs {
+        key,
+        value,
+        collect,
+    }: PivotWiderArgs,
+    context: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let input = context.input.into_vec().await;
+
+        let mut rows: Vec<WideRow> = vec![];
+
+        for row in input {
+            let (name, cell) = match (
+                get_data_by_key(&row, key.item[..].spanned(key.tag.span)),
+                get_data_by_key(&row, value.item[..].spanned(value.tag.span)),
+            ) {
+                (Some(name), Some(cell)) => (name, cell),
+                (None, _) => {
+                    yield Err(ShellError::labeled_error("Unknown column", "row does not contain this column", &key.tag));
+                    return;
+                }
+                (_, None) => {
+                    yield Err(ShellError::labeled_error("Unknown column", "row does not contain this column", &value.tag));
+                    return;
+                }
+            };
+
+            let name = match name.as_string() {
+                Ok(name) => name,
+                Err(err) => {
+                    yield Err(err);
+                    return;
+                }
+            };
+
+            // Rows agreeing on every other column are spread into the same wide row
+            let identity: Vec<(String, Value)> = row
+                .data_descriptors()
+                .into_iter()
+                .filter(|desc| *desc != key.item && *desc != value.item)
+                .filter_map(|desc| {
+                    let cell = get_data_by_key(&row, desc[..].spanned_unknown())?;
+                    Some((desc, cell))
+                })
+                .collect();
+
+            let position = rows.iter().position(|wide| {
+                wide.identity.len() == identity.len()
+                    && wide
+                        .identity
+                        .iter()
+                        .zip(identity.iter())
+                        .all(|((a, x), (b, y))| a == b && x.value == y.value)
+            });
+
+            let wide = match position {
+                Some(position) => &mut rows[position],
+                None => {
+                    rows.push(WideRow {
+                        tag: row.tag.clone(),
+                        identity,
+                        spread: IndexMap::new(),
+                    });
+                    rows.last_mut().unwrap()
+                }
+            };
+
+            wide.spread.entry(name).or_insert_with(Vec::new).push(cell);
+        }
+
+        for wide in rows {
+            let mut dict = TaggedDictBuilder::new(&wide.tag);
+
+            for (desc, cell) in wide.identity {
+                dict.insert_value(desc, cell);
+            }
+
+            for (name, mut cells) in wide.spread {
+                if collect {
+                    dict.insert_untagged(name, UntaggedValue::Table(cells));
+                } else if let Some(cell) = cells.pop() {
+                    dict.insert_value(name, cell);
+                }
+            }
+
+            yield ReturnSuccess::value(dict.into_value());
+        }
+    };
+
+    Ok(OutputStream::new(stream))
+}
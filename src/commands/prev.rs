@@ -29,5 +29,5 @@ impl WholeStreamCommand for Previous {
 }
 
 fn previous(_args: CommandArgs, _registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
-    Ok(vec![Ok(ReturnSuccess::Action(CommandAction::PreviousShell))].into())
+    Ok(vec![ReturnSuccess::action(CommandAction::PreviousShell)].into())
 }
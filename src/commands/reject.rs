@@ -1,13 +1,12 @@
 use crate::commands::WholeStreamCommand;
-use crate::data::base::reject_fields;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Signature, SyntaxShape};
-use nu_source::Tagged;
+use nu_protocol::{ColumnPath, Signature, SyntaxShape};
+use nu_value_ext::remove_data_at_column_path;
 
 #[derive(Deserialize)]
 pub struct RejectArgs {
-    rest: Vec<Tagged<String>>,
+    rest: Vec<ColumnPath>,
 }
 
 pub struct Reject;
@@ -18,7 +17,7 @@ impl WholeStreamCommand for Reject {
     }
 
     fn signature(&self) -> Signature {
-        Signature::build("reject").rest(SyntaxShape::Member, "the names of columns to remove")
+        Signature::build("reject").rest(SyntaxShape::ColumnPath, "the column paths to remove")
     }
 
     fn usage(&self) -> &str {
@@ -46,11 +45,12 @@ fn reject(
         ));
     }
 
-    let fields: Vec<_> = fields.iter().map(|f| f.item.clone()).collect();
-
-    let stream = input
-        .values
-        .map(move |item| reject_fields(&item, &fields, &item.tag));
+    // Paths that aren't there leave the row as it is
+    let stream = input.values.map(move |item| {
+        fields.iter().fold(item, |item, path| {
+            remove_data_at_column_path(&item, path).unwrap_or(item)
+        })
+    });
 
     Ok(stream.from_input_stream())
 }
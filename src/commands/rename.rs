@@ -0,0 +1,69 @@
+use crate::commands::PerItemCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{CallInfo, Signature, SyntaxShape, UntaggedValue, Value};
+
+pub struct Rename;
+
+impl PerItemCommand for Rename {
+    fn name(&self) -> &str {
+        "rename"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("rename")
+            .required("from", SyntaxShape::Member, "the column to rename")
+            .required("to", SyntaxShape::Member, "the new name for the column")
+    }
+
+    fn usage(&self) -> &str {
+        "Rename a column, keeping it in the same position."
+    }
+
+    fn run(
+        &self,
+        call_info: &CallInfo,
+        _registry: &CommandRegistry,
+        _raw_args: &RawCommandArgs,
+        value: Value,
+    ) -> Result<OutputStream, ShellError> {
+        let from = call_info.args.expect_nth(0)?;
+        let to = call_info.args.expect_nth(1)?;
+        let from_name = from.as_forgiving_string()?;
+        let to_name = to.as_forgiving_string()?;
+
+        match value {
+            Value {
+                value: UntaggedValue::Row(mut row),
+                tag,
+            } => {
+                if !row.contains_key(from_name) {
+                    return Err(ShellError::labeled_error(
+                        format!("Could not find column {}", from_name),
+                        "not a column of the row",
+                        &from.tag,
+                    ));
+                }
+
+                if from_name != to_name && row.contains_key(to_name) {
+                    return Err(ShellError::labeled_error(
+                        format!("Column {} already exists", to_name),
+                        "already a column of the row",
+                        &to.tag,
+                    ));
+                }
+
+                row.rename_key(from_name, to_name)?;
+
+                Ok(OutputStream::once(UntaggedValue::Row(row).into_value(tag)))
+            }
+
+            other => Err(ShellError::labeled_error(
+                "Unrecognized type in stream",
+                "original value",
+                other.tag,
+            )),
+        }
+    }
+}
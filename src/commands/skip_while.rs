@@ -2,7 +2,7 @@ use crate::commands::WholeStreamCommand;
 use crate::prelude::*;
 use log::trace;
 use nu_errors::ShellError;
-use nu_protocol::{Evaluate, Scope, Signature, SyntaxShape};
+use nu_protocol::{Evaluate, ReturnSuccess, Scope, Signature, SyntaxShape};
 
 pub struct SkipWhile;
 
@@ -43,18 +43,29 @@ pub fn skip_while(
     SkipWhileArgs { condition }: SkipWhileArgs,
     RunnableContext { input, .. }: RunnableContext,
 ) -> Result<OutputStream, ShellError> {
-    let objects = input.values.skip_while(move |item| {
-        trace!("ITEM = {:?}", item);
-        let result = condition.invoke(&Scope::new(item.clone()));
-        trace!("RESULT = {:?}", result);
+    let stream = async_stream! {
+        let mut input = input.values;
+        let mut skipping = true;
 
-        let return_value = match result {
-            Ok(ref v) if v.is_true() => true,
-            _ => false,
-        };
+        while let Some(item) = input.next().await {
+            if skipping {
+                trace!("ITEM = {:?}", item);
+                let result = condition.invoke(&Scope::new(item.clone()));
+                trace!("RESULT = {:?}", result);
 
-        futures::future::ready(return_value)
-    });
+                match result {
+                    Ok(ref v) if v.is_true() => continue,
+                    Ok(_) => skipping = false,
+                    Err(err) => {
+                        yield Err(err);
+                        break;
+                    }
+                }
+            }
 
-    Ok(objects.from_input_stream())
+            yield ReturnSuccess::value(item);
+        }
+    };
+
+    Ok(stream.to_output_stream())
 }
@@ -0,0 +1,128 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use nu_errors::ShellError;
+use nu_protocol::{
+    ColumnPath, ReturnSuccess, Signature, SyntaxShape, TaggedDictBuilder, UntaggedValue, Value,
+};
+use nu_source::{SpannedItem, Tagged};
+use nu_value_ext::{get_data_by_key, ValueExt};
+
+#[derive(Deserialize)]
+struct StrJoinArgs {
+    rest: Vec<Tagged<String>>,
+    into: ColumnPath,
+    separator: Option<Tagged<String>>,
+    strict: bool,
+}
+
+pub struct StrJoin;
+
+impl WholeStreamCommand for StrJoin {
+    fn name(&self) -> &str {
+        "str-join"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("str-join")
+            .required_named(
+                "into",
+                SyntaxShape::ColumnPath,
+                "the column to store the joined string in",
+            )
+            .named(
+                "separator",
+                SyntaxShape::String,
+                "the string placed between the joined columns (defaults to none)",
+            )
+            .switch(
+                "strict",
+                "error on missing columns instead of treating them as empty",
+            )
+            .rest(
+                SyntaxShape::Member,
+                "the columns to join, in order (joins the input values themselves if none are given)",
+            )
+    }
+
+    fn usage(&self) -> &str {
+        "Join the string contents of several columns into a new column."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, str_join)?.run()
+    }
+}
+
+fn str_join(
+    StrJoinArgs {
+        rest: columns,
+        into,
+        separator,
+        strict,
+    }: StrJoinArgs,
+    context: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let separator = separator.map(|s| s.item).unwrap_or_default();
+
+    // With no columns to join, the whole input is joined into a single new row
+    if columns.is_empty() {
+        let name = context.name.clone();
+
+        let stream = async_stream! {
+            match context.collect_strings().await {
+                Ok(strings) => {
+                    let joined = UntaggedValue::string(strings.join(&separator)).into_value(&name);
+                    let row = TaggedDictBuilder::new(&name).into_value();
+
+                    yield row
+                        .insert_data_at_column_path_creating(&into, joined)
+                        .map(ReturnSuccess::Value);
+                }
+                Err(err) => yield Err(err),
+            }
+        };
+
+        return Ok(stream.to_output_stream());
+    }
+
+    Ok(context
+        .input
+        .values
+        .map(move |row| {
+            let joined = join_columns(&row, &columns, &separator, strict)?;
+            let joined = UntaggedValue::string(joined).into_value(&row.tag);
+
+            ReturnSuccess::value(row.insert_data_at_column_path(&into, joined)?)
+        })
+        .to_output_stream())
+}
+
+fn join_columns(
+    row: &Value,
+    columns: &[Tagged<String>],
+    separator: &str,
+    strict: bool,
+) -> Result<String, ShellError> {
+    let mut parts = vec![];
+
+    for column in columns {
+        match get_data_by_key(row, column.item[..].spanned(column.tag.span)) {
+            Some(value) => parts.push(value.as_string()?),
+            None if strict => {
+                return Err(ShellError::labeled_error(
+                    "Unknown column",
+                    "row does not contain this column",
+                    &column.tag,
+                ))
+            }
+            // Missing columns count as empty strings
+            None => parts.push(String::new()),
+        }
+    }
+
+    Ok(parts.join(separator))
+}
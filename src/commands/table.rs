@@ -2,7 +2,7 @@ use crate::commands::WholeStreamCommand;
 use crate::format::TableView;
 use crate::prelude::*;
 use nu_errors::ShellError;
-use nu_protocol::{Primitive, ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
+use nu_protocol::{ReturnSuccess, Signature, SyntaxShape, UntaggedValue, Value};
 
 pub struct Table;
 
@@ -34,17 +34,14 @@ impl WholeStreamCommand for Table {
 
 fn table(args: CommandArgs, registry: &CommandRegistry) -> Result<OutputStream, ShellError> {
     let args = args.evaluate_once(registry)?;
+    let start_number = args
+        .call_info
+        .args
+        .get_opt::<u64>("start_number")?
+        .unwrap_or(0) as usize;
 
     let stream = async_stream! {
         let host = args.host.clone();
-        let start_number = match args.get("start_number") {
-            Some(Value { value: UntaggedValue::Primitive(Primitive::Int(i)), .. }) => {
-                i.to_usize().unwrap()
-            }
-            _ => {
-                0
-            }
-        };
 
         let input: Vec<Value> = args.input.into_vec().await;
         if input.len() > 0 {
@@ -0,0 +1,66 @@
+use crate::commands::WholeStreamCommand;
+use crate::prelude::*;
+use log::trace;
+use nu_errors::ShellError;
+use nu_protocol::{Evaluate, ReturnSuccess, Scope, Signature, SyntaxShape};
+
+pub struct TakeWhile;
+
+#[derive(Deserialize)]
+pub struct TakeWhileArgs {
+    condition: Evaluate,
+}
+
+impl WholeStreamCommand for TakeWhile {
+    fn name(&self) -> &str {
+        "take-while"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("take-while")
+            .required(
+                "condition",
+                SyntaxShape::Block,
+                "the condition that must be met to keep taking rows",
+            )
+            .filter()
+    }
+
+    fn usage(&self) -> &str {
+        "Takes rows while the condition matches."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, take_while)?.run()
+    }
+}
+
+pub fn take_while(
+    TakeWhileArgs { condition }: TakeWhileArgs,
+    RunnableContext { input, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let mut input = input.values;
+
+        while let Some(item) = input.next().await {
+            trace!("ITEM = {:?}", item);
+            let result = condition.invoke(&Scope::new(item.clone()));
+            trace!("RESULT = {:?}", result);
+
+            match result {
+                Ok(ref v) if v.is_true() => yield ReturnSuccess::value(item),
+                Ok(_) => break,
+                Err(err) => {
+                    yield Err(err);
+                    break;
+                }
+            }
+        }
+    };
+
+    Ok(stream.to_output_stream())
+}
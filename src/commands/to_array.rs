@@ -0,0 +1,46 @@
+use crate::commands::WholeStreamCommand;
+use crate::context::CommandRegistry;
+use crate::prelude::*;
+use futures::stream::StreamExt;
+use nu_errors::ShellError;
+use nu_protocol::{ReturnSuccess, Signature, UntaggedValue, Value};
+
+pub struct ToArray;
+
+#[derive(Deserialize)]
+pub struct ToArrayArgs {}
+
+impl WholeStreamCommand for ToArray {
+    fn name(&self) -> &str {
+        "to-array"
+    }
+
+    fn signature(&self) -> Signature {
+        Signature::build("to-array")
+    }
+
+    fn usage(&self) -> &str {
+        "Collect the rows into a single table value."
+    }
+
+    fn run(
+        &self,
+        args: CommandArgs,
+        registry: &CommandRegistry,
+    ) -> Result<OutputStream, ShellError> {
+        args.process(registry, to_array)?.run()
+    }
+}
+
+pub fn to_array(
+    ToArrayArgs {}: ToArrayArgs,
+    RunnableContext { input, name, .. }: RunnableContext,
+) -> Result<OutputStream, ShellError> {
+    let stream = async_stream! {
+        let rows: Vec<Value> = input.values.collect().await;
+
+        yield ReturnSuccess::value(UntaggedValue::Table(rows).into_value(name))
+    };
+
+    Ok(stream.to_output_stream())
+}
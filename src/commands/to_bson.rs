@@ -45,7 +45,9 @@ pub fn value_to_bson_value(v: &Value) -> Result<Bson, ShellError> {
                 .to_f64()
                 .expect("Unimplemented BUG: What about big decimals?"),
         ),
-        UntaggedValue::Primitive(Primitive::Duration(secs)) => Bson::I64(*secs as i64),
+        UntaggedValue::Primitive(Primitive::Duration(nanos)) => {
+            Bson::I64(nanos.tagged(&v.tag).coerce_into("converting to BSON")?)
+        }
         UntaggedValue::Primitive(Primitive::Date(d)) => Bson::UtcDatetime(*d),
         UntaggedValue::Primitive(Primitive::EndOfStream) => Bson::Null,
         UntaggedValue::Primitive(Primitive::BeginningOfStream) => Bson::Null,
@@ -63,6 +65,10 @@ pub fn value_to_bson_value(v: &Value) -> Result<Bson, ShellError> {
                     UnspannedPathMember::Int(int) => Ok(Bson::I64(
                         int.tagged(&v.tag).coerce_into("converting to BSON")?,
                     )),
+                    UnspannedPathMember::Wildcard => Ok(Bson::String("*".to_string())),
+                    UnspannedPathMember::Range { from, to } => {
+                        Ok(Bson::String(format!("{}..{}", from, to)))
+                    }
                 })
                 .collect::<Result<Vec<Bson>, ShellError>>()?,
         ),
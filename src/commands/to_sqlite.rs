@@ -84,12 +84,22 @@ fn get_columns(rows: &[Value]) -> Result<String, std::io::Error> {
     }
 }
 
-fn nu_value_to_sqlite_string(v: Value) -> String {
-    match &v.value {
+fn nu_value_to_sqlite_string(v: Value) -> Result<String, std::io::Error> {
+    Ok(match &v.value {
         UntaggedValue::Primitive(p) => match p {
             Primitive::Nothing => "NULL".into(),
             Primitive::Int(i) => format!("{}", i),
-            Primitive::Duration(u) => format!("{}", u),
+            // Durations are stored as a whole number of nanoseconds, which has to fit
+            // in an SQLite integer
+            Primitive::Duration(nanos) => match nanos.to_i64() {
+                Some(nanos) => format!("{}", nanos),
+                None => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        "Duration is too long to store as nanoseconds in an SQLite integer",
+                    ))
+                }
+            },
             Primitive::Decimal(f) => format!("{}", f),
             Primitive::Bytes(u) => format!("{}", u),
             Primitive::Pattern(s) => format!("'{}'", s.replace("'", "''")),
@@ -106,20 +116,25 @@ fn nu_value_to_sqlite_string(v: Value) -> String {
             | Primitive::Range(_) => "NULL".into(),
         },
         _ => "NULL".into(),
-    }
+    })
 }
 
 fn get_insert_values(rows: Vec<Value>) -> Result<String, std::io::Error> {
     let values: Result<Vec<_>, _> = rows
         .into_iter()
         .map(|value| match value.value {
-            UntaggedValue::Row(d) => Ok(format!(
-                "({})",
-                d.entries
+            UntaggedValue::Row(d) => {
+                let values: Result<Vec<_>, _> = d
+                    .entries
                     .iter()
                     .map(|(_k, v)| nu_value_to_sqlite_string(v.clone()))
-                    .fold("".to_string(), comma_concat)
-            )),
+                    .collect();
+
+                Ok(format!(
+                    "({})",
+                    values?.into_iter().fold("".to_string(), comma_concat)
+                ))
+            }
             _ => Err(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 "Could not find table column names",
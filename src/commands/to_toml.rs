@@ -31,7 +31,11 @@ pub fn value_to_toml_value(v: &Value) -> Result<toml::Value, ShellError> {
     Ok(match &v.value {
         UntaggedValue::Primitive(Primitive::Boolean(b)) => toml::Value::Boolean(*b),
         UntaggedValue::Primitive(Primitive::Bytes(b)) => toml::Value::Integer(*b as i64),
-        UntaggedValue::Primitive(Primitive::Duration(d)) => toml::Value::Integer(*d as i64),
+        UntaggedValue::Primitive(Primitive::Duration(nanos)) => toml::Value::Integer(
+            nanos
+                .tagged(&v.tag)
+                .coerce_into("converting to TOML integer")?,
+        ),
         UntaggedValue::Primitive(Primitive::Date(d)) => toml::Value::String(d.to_string()),
         UntaggedValue::Primitive(Primitive::EndOfStream) => {
             toml::Value::String("<End of Stream>".to_string())
@@ -62,6 +66,10 @@ pub fn value_to_toml_value(v: &Value) -> Result<toml::Value, ShellError> {
                         int.tagged(&v.tag)
                             .coerce_into("converting to TOML integer")?,
                     )),
+                    UnspannedPathMember::Wildcard => Ok(toml::Value::String("*".to_string())),
+                    UnspannedPathMember::Range { from, to } => {
+                        Ok(toml::Value::String(format!("{}..{}", from, to)))
+                    }
                 })
                 .collect::<Result<Vec<toml::Value>, ShellError>>()?,
         ),
@@ -33,8 +33,11 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
         UntaggedValue::Primitive(Primitive::Bytes(b)) => {
             serde_yaml::Value::Number(serde_yaml::Number::from(b.to_f64().unwrap()))
         }
-        UntaggedValue::Primitive(Primitive::Duration(secs)) => {
-            serde_yaml::Value::Number(serde_yaml::Number::from(secs.to_f64().unwrap()))
+        UntaggedValue::Primitive(Primitive::Duration(nanos)) => {
+            serde_yaml::Value::Number(serde_yaml::Number::from(CoerceInto::<i64>::coerce_into(
+                nanos.tagged(&v.tag),
+                "converting to YAML number",
+            )?))
         }
         UntaggedValue::Primitive(Primitive::Date(d)) => serde_yaml::Value::String(d.to_string()),
         UntaggedValue::Primitive(Primitive::EndOfStream) => serde_yaml::Value::Null,
@@ -66,6 +69,12 @@ pub fn value_to_yaml_value(v: &Value) -> Result<serde_yaml::Value, ShellError> {
                             "converting to YAML number",
                         )?),
                     )),
+                    UnspannedPathMember::Wildcard => {
+                        out.push(serde_yaml::Value::String("*".to_string()))
+                    }
+                    UnspannedPathMember::Range { from, to } => {
+                        out.push(serde_yaml::Value::String(format!("{}..{}", from, to)))
+                    }
                 }
             }
 
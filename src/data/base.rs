@@ -15,7 +15,7 @@ use nu_protocol::{
 use nu_source::{Tag, Text};
 use nu_value_ext::ValueExt;
 use num_bigint::BigInt;
-use num_traits::Zero;
+use num_traits::{ToPrimitive, Zero};
 use query_interface::{interfaces, vtable_for, ObjectHash};
 use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
@@ -106,28 +106,12 @@ pub(crate) fn select_fields(obj: &Value, fields: &[String], tag: impl Into<Tag>)
     out.into_value()
 }
 
-pub(crate) fn reject_fields(obj: &Value, fields: &[String], tag: impl Into<Tag>) -> Value {
-    let mut out = TaggedDictBuilder::new(tag);
-
-    let descs = obj.data_descriptors();
-
-    for desc in descs {
-        if fields.iter().any(|field| *field == desc) {
-            continue;
-        } else {
-            out.insert_value(desc.clone(), obj.get_data(&desc).borrow().clone())
-        }
-    }
-
-    out.into_value()
-}
-
 pub(crate) enum CompareValues {
     Ints(BigInt, BigInt),
     Decimals(BigDecimal, BigDecimal),
     String(String, String),
     Date(DateTime<Utc>, DateTime<Utc>),
-    DateDuration(DateTime<Utc>, u64),
+    DateDuration(DateTime<Utc>, BigInt),
 }
 
 impl CompareValues {
@@ -140,9 +124,17 @@ impl CompareValues {
             CompareValues::DateDuration(left, right) => {
                 use std::time::Duration;
 
-                // Create the datetime we're comparing against, as duration is an offset from now
-                let right: DateTime<Utc> = (SystemTime::now() - Duration::from_secs(*right)).into();
-                right.cmp(left)
+                // Create the datetime we're comparing against, as duration is an offset from now.
+                // Durations reaching back past the earliest representable time are older than
+                // any date.
+                let right = right
+                    .to_u64()
+                    .and_then(|nanos| SystemTime::now().checked_sub(Duration::from_nanos(nanos)));
+
+                match right {
+                    Some(right) => DateTime::<Utc>::from(right).cmp(left),
+                    None => std::cmp::Ordering::Less,
+                }
             }
         }
     }
@@ -189,7 +181,7 @@ fn coerce_compare_primitive(
         (String(left), Line(right)) => CompareValues::String(left.clone(), right.clone()),
         (Line(left), Line(right)) => CompareValues::String(left.clone(), right.clone()),
         (Date(left), Date(right)) => CompareValues::Date(*left, *right),
-        (Date(left), Duration(right)) => CompareValues::DateDuration(*left, *right),
+        (Date(left), Duration(right)) => CompareValues::DateDuration(*left, right.clone()),
         _ => return Err((left.type_name(), right.type_name())),
     })
 }
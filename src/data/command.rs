@@ -1,7 +1,7 @@
 use crate::commands::command::Command;
 use crate::data::TaggedListBuilder;
 use crate::prelude::*;
-use nu_protocol::{NamedType, PositionalType, Signature, TaggedDictBuilder, UntaggedValue, Value};
+use nu_protocol::{NamedType, Signature, TaggedDictBuilder, UntaggedValue, Value};
 use std::ops::Deref;
 
 pub(crate) fn command_dict(command: Arc<Command>, tag: impl Into<Tag>) -> Value {
@@ -45,12 +45,12 @@ fn signature_dict(signature: Signature, tag: impl Into<Tag>) -> Value {
     let mut sig = TaggedListBuilder::new(&tag);
 
     for arg in signature.positional.iter() {
-        let is_required = match arg.0 {
-            PositionalType::Mandatory(_, _) => true,
-            PositionalType::Optional(_, _) => false,
-        };
-
-        sig.push_value(for_spec(arg.0.name(), "argument", is_required, &tag));
+        sig.push_value(for_spec(
+            arg.0.name(),
+            "argument",
+            arg.0.is_mandatory(),
+            &tag,
+        ));
     }
 
     if signature.rest_positional.is_some() {
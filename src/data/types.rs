@@ -1,13 +1,26 @@
 use crate::prelude::*;
 use log::trace;
 use nu_errors::{CoerceInto, ShellError};
-use nu_protocol::{Primitive, SpannedTypeName, UntaggedValue, Value};
+use nu_protocol::{EvaluatedArgs, Primitive, SpannedTypeName, UntaggedValue, Value};
 use nu_source::Tagged;
 
 pub trait ExtractType: Sized {
     fn extract(value: &Value) -> Result<Self, ShellError>;
 }
 
+pub trait EvaluatedArgsExt {
+    fn get_opt<T: ExtractType>(&self, name: &str) -> Result<Option<T>, ShellError>;
+}
+
+impl EvaluatedArgsExt for EvaluatedArgs {
+    fn get_opt<T: ExtractType>(&self, name: &str) -> Result<Option<T>, ShellError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(value) => Ok(Some(T::extract(value)?)),
+        }
+    }
+}
+
 impl<T: ExtractType> ExtractType for Tagged<T> {
     fn extract(value: &Value) -> Result<Tagged<T>, ShellError> {
         let name = std::any::type_name::<T>();
@@ -94,3 +107,38 @@ impl ExtractType for String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::EvaluatedArgsExt;
+    use indexmap::IndexMap;
+    use nu_protocol::{EvaluatedArgs, UntaggedValue};
+
+    fn args_with_depth(depth: UntaggedValue) -> EvaluatedArgs {
+        let mut named = IndexMap::new();
+        named.insert("depth".to_string(), depth.into_untagged_value());
+
+        EvaluatedArgs::new(None, Some(named))
+    }
+
+    #[test]
+    fn gets_a_present_optional_integer() {
+        let args = args_with_depth(UntaggedValue::int(3));
+
+        assert_eq!(args.get_opt::<i64>("depth").unwrap(), Some(3));
+    }
+
+    #[test]
+    fn gets_nothing_for_an_absent_optional_integer() {
+        let args = EvaluatedArgs::new(None, None);
+
+        assert_eq!(args.get_opt::<i64>("depth").unwrap(), None);
+    }
+
+    #[test]
+    fn errors_on_an_optional_integer_of_the_wrong_type() {
+        let args = args_with_depth(UntaggedValue::string("deep"));
+
+        assert!(args.get_opt::<i64>("depth").is_err());
+    }
+}
@@ -5,6 +5,7 @@ use crate::prelude::*;
 use log::trace;
 use nu_errors::{ArgumentError, ShellError};
 use nu_parser::hir::{self, Expression, RawExpression};
+use nu_parser::Unit;
 use nu_protocol::{
     ColumnPath, Evaluate, Primitive, RangeInclusion, Scope, TaggedDictBuilder, UnspannedPathMember,
     UntaggedValue, Value,
@@ -22,7 +23,7 @@ pub(crate) fn evaluate_baseline_expr(
         anchor: None,
     };
     match &expr.expr {
-        RawExpression::Literal(literal) => Ok(evaluate_literal(literal, source)),
+        RawExpression::Literal(literal) => evaluate_literal(literal, source),
         RawExpression::ExternalWord => Err(ShellError::argument_error(
             "Invalid external word".spanned(tag.span),
             ArgumentError::InvalidExternalWord,
@@ -126,8 +127,8 @@ pub(crate) fn evaluate_baseline_expr(
     }
 }
 
-fn evaluate_literal(literal: &hir::Literal, source: &Text) -> Value {
-    match &literal.literal {
+fn evaluate_literal(literal: &hir::Literal, source: &Text) -> Result<Value, ShellError> {
+    let value = match &literal.literal {
         hir::RawLiteral::ColumnPath(path) => {
             let members = path
                 .iter()
@@ -143,7 +144,13 @@ fn evaluate_literal(literal: &hir::Literal, source: &Text) -> Value {
                 UntaggedValue::decimal(d.clone()).into_value(literal.span)
             }
         },
-        hir::RawLiteral::Size(int, unit) => unit.compute(&int).into_value(literal.span),
+        hir::RawLiteral::Size(int, unit) => unit
+            .compute(int.spanned(literal.span))?
+            .into_value(literal.span),
+        hir::RawLiteral::CompoundSize => {
+            Unit::parse_compound(literal.span.slice(source).spanned(literal.span))?
+                .into_value(literal.span)
+        }
         hir::RawLiteral::String(tag) => {
             UntaggedValue::string(tag.slice(source)).into_value(literal.span)
         }
@@ -153,7 +160,9 @@ fn evaluate_literal(literal: &hir::Literal, source: &Text) -> Value {
         hir::RawLiteral::Bare => {
             UntaggedValue::string(literal.span.slice(source)).into_value(literal.span)
         }
-    }
+    };
+
+    Ok(value)
 }
 
 fn evaluate_reference(
@@ -21,22 +21,43 @@ macro_rules! stream {
     }}
 }
 
+#[macro_export]
+macro_rules! input_stream {
+    ($($expr:expr),* $(,)?) => {{
+        let mut v: std::collections::VecDeque<nu_protocol::Value> =
+            std::collections::VecDeque::new();
+
+        $(
+            v.push_back($expr);
+        )*
+
+        $crate::stream::InputStream::from(v)
+    }}
+}
+
+#[macro_export]
+macro_rules! output_stream {
+    ($($expr:expr),* $(,)?) => {{
+        let mut v: std::collections::VecDeque<nu_protocol::ReturnValue> =
+            std::collections::VecDeque::new();
+
+        $(
+            v.push_back($expr.into());
+        )*
+
+        $crate::stream::OutputStream::from(v)
+    }}
+}
+
+/// Traces every item of an `InputStream` or an `OutputStream` as it flows by, when tracing is
+/// enabled for `target`. Evaluates to a stream of the same kind.
 #[macro_export]
 macro_rules! trace_stream {
     (target: $target:tt, $desc:tt = $expr:expr) => {{
         if log::log_enabled!(target: $target, log::Level::Trace) {
-            use futures::stream::StreamExt;
-
-            let objects = $expr.values.inspect(move |o| {
-                trace!(
-                    target: $target,
-                    "{} = {}",
-                    $desc,
-                    nu_source::PrettyDebug::plain_string(o, 70)
-                );
-            });
-
-            $crate::stream::InputStream::from_stream(objects.boxed())
+            $crate::prelude::TraceStream::traced($expr, move |item| {
+                log::trace!(target: $target, "{} = {}", $desc, item);
+            })
         } else {
             $expr
         }
@@ -45,27 +66,9 @@ macro_rules! trace_stream {
 
 #[macro_export]
 macro_rules! trace_out_stream {
-    (target: $target:tt, $desc:tt = $expr:expr) => {{
-        if log::log_enabled!(target: $target, log::Level::Trace) {
-            use futures::stream::StreamExt;
-
-            let objects = $expr.values.inspect(move |o| {
-                trace!(
-                    target: $target,
-                    "{} = {}",
-                    $desc,
-                    match o {
-                        Err(err) => format!("{:?}", err),
-                        Ok(value) => value.display(),
-                    }
-                );
-            });
-
-            $crate::stream::OutputStream::new(objects)
-        } else {
-            $expr
-        }
-    }};
+    (target: $target:tt, $desc:tt = $expr:expr) => {
+        trace_stream!(target: $target, $desc = $expr)
+    };
 }
 
 pub(crate) use nu_protocol::{errln, outln};
@@ -75,7 +78,7 @@ pub(crate) use crate::commands::command::{
 };
 pub(crate) use crate::context::CommandRegistry;
 pub(crate) use crate::context::Context;
-pub(crate) use crate::data::types::ExtractType;
+pub(crate) use crate::data::types::{EvaluatedArgsExt, ExtractType};
 pub(crate) use crate::data::value;
 pub(crate) use crate::env::host::handle_unexpected;
 pub(crate) use crate::env::Host;
@@ -119,7 +122,11 @@ where
 }
 
 pub trait ToInputStream {
+    /// Errors are forwarded as error values in their place, and the stream carries on after them.
     fn to_input_stream(self) -> InputStream;
+
+    /// Like `to_input_stream`, but ends the stream at the first error.
+    fn to_input_stream_checked(self) -> InputStream;
 }
 
 impl<T, U> ToInputStream for T
@@ -129,9 +136,59 @@ where
 {
     fn to_input_stream(self) -> InputStream {
         InputStream {
-            values: self.map(|item| item.into().unwrap()).boxed(),
+            values: self
+                .map(|item| match item.into() {
+                    Ok(value) => value,
+                    Err(err) => nu_protocol::UntaggedValue::Error(err).into_untagged_value(),
+                })
+                .boxed(),
         }
     }
+
+    fn to_input_stream_checked(self) -> InputStream {
+        let mut items = self.map(|item| item.into()).boxed();
+
+        let stream = async_stream! {
+            while let Some(item) = items.next().await {
+                match item {
+                    Ok(value) => yield value,
+                    Err(err) => {
+                        yield nu_protocol::UntaggedValue::Error(err).into_untagged_value();
+                        break;
+                    }
+                }
+            }
+        };
+
+        InputStream {
+            values: stream.boxed(),
+        }
+    }
+}
+
+/// The streams `trace_stream!` accepts, each rendering its own kind of item for the trace.
+pub trait TraceStream {
+    fn traced(self, trace: impl Fn(String) + Send + 'static) -> Self;
+}
+
+impl TraceStream for InputStream {
+    fn traced(self, trace: impl Fn(String) + Send + 'static) -> InputStream {
+        InputStream::from_stream(
+            self.values
+                .inspect(move |value| trace(PrettyDebug::plain_string(value, 70))),
+        )
+    }
+}
+
+impl TraceStream for OutputStream {
+    fn traced(self, trace: impl Fn(String) + Send + 'static) -> OutputStream {
+        OutputStream::new(self.values.inspect(move |item| {
+            trace(match item {
+                Err(err) => format!("{:?}", err),
+                Ok(value) => value.display(),
+            })
+        }))
+    }
 }
 
 pub trait ToOutputStream {
@@ -149,3 +206,109 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ToInputStream, TraceStream};
+    use futures::executor::block_on;
+    use futures::stream::{self, StreamExt};
+    use nu_errors::ShellError;
+    use nu_protocol::{ReturnSuccess, ReturnValue, UntaggedValue, Value};
+
+    fn int(i: i64) -> Value {
+        UntaggedValue::int(i).into_untagged_value()
+    }
+
+    #[test]
+    fn checked_input_streams_yield_the_first_error_instead_of_panicking() {
+        let error = ShellError::untagged_runtime_error("broken pipe");
+        let items: Vec<Result<Value, ShellError>> = vec![
+            Ok(int(1)),
+            Err(error.clone()),
+            Ok(int(2)),
+            Err(ShellError::untagged_runtime_error("never reached")),
+        ];
+
+        let values = block_on(stream::iter(items).to_input_stream_checked().into_vec());
+
+        assert_eq!(
+            values,
+            vec![int(1), UntaggedValue::Error(error).into_untagged_value()]
+        );
+    }
+
+    #[test]
+    fn input_streams_forward_errors_instead_of_panicking() {
+        let error = ShellError::untagged_runtime_error("could not read the file");
+        let items: Vec<Result<Value, ShellError>> =
+            vec![Ok(int(1)), Ok(int(2)), Err(error.clone()), Ok(int(4))];
+
+        let values = block_on(stream::iter(items).to_input_stream().into_vec());
+
+        assert_eq!(
+            values,
+            vec![
+                int(1),
+                int(2),
+                UntaggedValue::Error(error).into_untagged_value(),
+                int(4)
+            ]
+        );
+    }
+
+    #[test]
+    fn input_stream_macro_builds_an_input_stream() {
+        let values = block_on(input_stream![int(1), int(2), int(3)].into_vec());
+
+        assert_eq!(values, vec![int(1), int(2), int(3)]);
+    }
+
+    #[test]
+    fn output_stream_macro_builds_an_output_stream() {
+        let stream = output_stream![int(1), ReturnSuccess::value(int(2))];
+        let values: Vec<ReturnValue> = block_on(stream.values.collect());
+        let values: Vec<Value> = values
+            .into_iter()
+            .map(|item| match item {
+                Ok(ReturnSuccess::Value(value)) => value,
+                _ => panic!("expected only values in the output stream"),
+            })
+            .collect();
+
+        assert_eq!(values, vec![int(1), int(2)]);
+    }
+
+    #[test]
+    fn checked_input_streams_pass_values_through() {
+        let items: Vec<Result<Value, ShellError>> = vec![Ok(int(1)), Ok(int(2))];
+
+        let values = block_on(stream::iter(items).to_input_stream_checked().into_vec());
+
+        assert_eq!(values, vec![int(1), int(2)]);
+    }
+
+    #[test]
+    fn trace_stream_macro_accepts_both_stream_kinds() {
+        let input = trace_stream!(target: "nu::test", "input" = input_stream![int(1)]);
+        let output = trace_stream!(target: "nu::test", "output" = output_stream![int(2)]);
+
+        assert_eq!(block_on(input.into_vec()), vec![int(1)]);
+        assert_eq!(block_on(output.values.collect::<Vec<_>>()).len(), 1);
+    }
+
+    #[test]
+    fn traced_streams_render_each_item_as_it_passes() {
+        let traced = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+
+        let seen = traced.clone();
+        let input = input_stream![int(1)].traced(move |item| seen.lock().unwrap().push(item));
+        let seen = traced.clone();
+        let output = output_stream![Err(ShellError::untagged_runtime_error("broken pipe"))]
+            .traced(move |item| seen.lock().unwrap().push(item));
+
+        assert_eq!(block_on(input.into_vec()), vec![int(1)]);
+        assert_eq!(block_on(output.values.collect::<Vec<_>>()).len(), 1);
+        assert_eq!(traced.lock().unwrap()[0], "1");
+        assert!(traced.lock().unwrap()[1].contains("broken pipe"));
+    }
+}
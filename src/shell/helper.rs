@@ -147,6 +147,7 @@ fn paint_flat_shape(flat_shape: &Spanned<FlatShape>, line: &str) -> String {
         FlatShape::ShorthandFlag => Color::Black.bold(),
         FlatShape::Int => Color::Purple.bold(),
         FlatShape::Decimal => Color::Purple.bold(),
+        FlatShape::CompoundSize => Color::Purple.bold(),
         FlatShape::Whitespace | FlatShape::Separator => Color::White.normal(),
         FlatShape::Comment => Color::Black.bold(),
         FlatShape::Error => Color::Red.bold(),
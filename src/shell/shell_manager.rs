@@ -61,6 +61,11 @@ impl ShellManager {
         self.set_path(self.path());
     }
 
+    pub fn remove_all(&mut self) {
+        self.shells.lock().unwrap().clear();
+        self.current_shell.store(0, Ordering::SeqCst);
+    }
+
     pub fn is_empty(&self) -> bool {
         self.shells.lock().unwrap().is_empty()
     }
@@ -71,6 +71,7 @@ impl OutputStream {
         }
     }
 
+    /// A stream that ends without yielding anything.
     pub fn empty() -> OutputStream {
         let v: VecDeque<ReturnValue> = VecDeque::new();
         v.into()
@@ -82,6 +83,13 @@ impl OutputStream {
         v.into()
     }
 
+    /// A stream yielding `value` as its only item, for commands that produce a single value.
+    pub fn once(value: Value) -> OutputStream {
+        OutputStream::one(ReturnSuccess::value(value))
+    }
+
+    /// Yields each input value as a successful return value. An input that ends without
+    /// yielding anything gives an output that does the same, like `OutputStream::empty()`.
     pub fn from_input(input: impl Stream<Item = Value> + Send + 'static) -> OutputStream {
         OutputStream {
             values: input.map(ReturnSuccess::value).boxed(),
@@ -170,3 +178,44 @@ impl From<Vec<Value>> for OutputStream {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{InputStream, OutputStream};
+    use futures::executor::block_on;
+    use futures::stream::StreamExt;
+    use nu_protocol::{ReturnSuccess, ReturnValue, UntaggedValue, Value};
+
+    fn int(i: i64) -> Value {
+        UntaggedValue::int(i).into_untagged_value()
+    }
+
+    fn values(stream: OutputStream) -> Vec<Value> {
+        let items: Vec<ReturnValue> = block_on(stream.values.collect());
+
+        items
+            .into_iter()
+            .map(|item| match item {
+                Ok(ReturnSuccess::Value(value)) => value,
+                _ => panic!("expected only values in the output stream"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn empty_output_streams_yield_nothing() {
+        assert_eq!(values(OutputStream::empty()), vec![]);
+    }
+
+    #[test]
+    fn output_streams_from_an_empty_input_yield_nothing() {
+        let input = InputStream::from(Vec::<Value>::new());
+
+        assert_eq!(values(OutputStream::from_input(input)), vec![]);
+    }
+
+    #[test]
+    fn once_yields_a_single_value() {
+        assert_eq!(values(OutputStream::once(int(7))), vec![int(7)]);
+    }
+}
@@ -50,3 +50,56 @@ fn discards_empty_rows_by_default() {
         assert_eq!(actual, "4");
     });
 }
+
+#[test]
+fn discards_empty_strings_and_tables_too() {
+    Playground::setup("compact_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "values.json",
+            r#"
+                ["a", "", null, [], "b"]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open values.json
+                | compact
+                | to-json
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, r#"["a","b"]"#);
+    });
+}
+
+#[test]
+fn discards_rows_where_given_column_is_an_empty_string() {
+    Playground::setup("compact_test_4", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.json",
+            r#"
+                [
+                    {"name": "Yehuda"},
+                    {"name": ""},
+                    {"name": null},
+                    {"rusty_luck": 1}
+                ]
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.json
+                | compact name
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Yehuda");
+    });
+}
@@ -0,0 +1,20 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn computes_the_duration_between_two_date_columns() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | get package
+            | insert started "2019-12-01 10:00"
+            | insert finished "2019-12-01 11:30"
+            | str started --to-date-time --format "%Y-%m-%d %H:%M"
+            | str finished --to-date-time --format "%Y-%m-%d %H:%M"
+            | date-diff started finished
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "5400000000000");
+}
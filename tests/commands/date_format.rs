@@ -0,0 +1,34 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn formats_a_date_with_the_given_pattern() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo "2019-12-01"
+            | str --to-date-time --format "%Y-%m-%d"
+            | date-format "%d/%m/%Y"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "01/12/2019");
+}
+
+#[test]
+fn formats_the_date_in_the_given_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | get package
+            | insert released "2019-12-01"
+            | str released --to-date-time --format "%Y-%m-%d"
+            | date-format "%Y" released
+            | get released
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2019");
+}
@@ -0,0 +1,193 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn joins_strings_without_a_separator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo --no-separator "nu" "shell"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nushell");
+}
+
+#[test]
+fn joins_strings_without_a_separator_with_the_short_flag() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo -n "a" "b"
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "ab");
+}
+
+#[test]
+fn errors_joining_strings_and_integers_without_a_separator() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo --no-separator "version" 7
+        "#
+    ));
+
+    assert!(actual.contains("Expected a string"), "actual={:?}", actual);
+}
+
+#[test]
+fn echoes_one_value_per_argument_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo a b c
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn joins_arguments_into_one_value_with_a_separator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo a b c --separator ", "
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+}
+
+#[test]
+fn joins_arguments_with_a_separator() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo a b c --separator ", "
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a, b, c");
+}
+
+#[test]
+fn echoes_a_table_row_by_row() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "3");
+}
+
+#[test]
+fn echoes_a_table_as_a_single_value_with_raw() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3] --raw
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+}
+
+#[test]
+fn echoes_a_table_as_a_single_value_with_short_raw() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3] -r
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+}
+
+#[test]
+fn joins_strings_with_a_short_separator_flag() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo -s ", " a b c
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a, b, c");
+}
+
+#[test]
+fn echoes_durations_longer_than_five_centuries() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1mil 600y
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "2");
+}
+
+#[test]
+fn echoes_a_compound_duration_as_the_sum_of_its_parts() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1h30m 90m
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[5400000000000,5400000000000]");
+}
+
+#[test]
+fn errors_on_a_flag_echo_does_not_declare() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo a b --verbse
+        "#
+    ));
+
+    assert!(actual.contains("Unknown flag"), "actual={:?}", actual);
+    assert!(
+        actual.contains("valid flags are --no-separator, --separator, --raw"),
+        "actual={:?}",
+        actual
+    );
+}
+
+#[test]
+fn errors_when_a_switch_after_the_values_is_given_a_value() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo a b --raw 3
+            | count
+        "#
+    ));
+
+    assert!(actual.contains("doesn't take a value"), "actual={:?}", actual);
+}
@@ -1,4 +1,4 @@
-use nu_test_support::{nu, pipeline};
+use nu_test_support::{nu, nu_error, pipeline};
 
 #[test]
 fn creates_a_new_table_with_the_new_row_given() {
@@ -14,3 +14,32 @@ fn creates_a_new_table_with_the_new_row_given() {
 
     assert_eq!(actual, "7");
 }
+
+#[test]
+fn edits_an_existing_top_level_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | get package
+            | edit name "nu"
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nu");
+}
+
+#[test]
+fn errors_instead_of_creating_a_missing_column() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | edit package.not_a_column "nu"
+        "#
+    ));
+
+    assert!(actual.contains("edit could not find place to insert column"));
+}
@@ -216,3 +216,90 @@ fn errors_fetching_by_index_out_of_bounds() {
         )
     })
 }
+
+#[test]
+fn fetches_a_column_from_every_row_with_a_wildcard() {
+    Playground::setup("get_test_9", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [[fortune_tellers]]
+                name = "Andrés N. Robalino"
+                arepas = 1
+
+                [[fortune_tellers]]
+                arepas = 1
+
+                [[fortune_tellers]]
+                name = "Yehuda Katz"
+                arepas = 1
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get fortune_tellers.*.name
+                | nth 1
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Yehuda Katz");
+    })
+}
+
+#[test]
+fn fetches_a_range_of_rows() {
+    Playground::setup("get_test_10", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [[fortune_tellers]]
+                name = "Andrés N. Robalino"
+
+                [[fortune_tellers]]
+                name = "Jonathan Turner"
+
+                [[fortune_tellers]]
+                name = "Yehuda Katz"
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get fortune_tellers.1..3.name
+                | nth 0
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Jonathan Turner");
+    })
+}
+
+#[test]
+fn errors_fetching_a_range_of_rows_from_a_row() {
+    Playground::setup("get_test_11", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                [[fortune_tellers]]
+                name = "Andrés N. Robalino"
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open sample.toml
+                | get fortune_tellers.0.0..1
+            "#
+        ));
+
+        assert!(actual.contains("Range on a row"));
+    })
+}
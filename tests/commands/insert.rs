@@ -1,4 +1,4 @@
-use nu_test_support::{nu, pipeline};
+use nu_test_support::{nu, nu_error, pipeline};
 
 #[test]
 fn insert_plugin() {
@@ -14,3 +14,46 @@ fn insert_plugin() {
 
     assert_eq!(actual, "1");
 }
+
+#[test]
+fn insert_creates_missing_intermediate_rows() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | insert package.metadata.docs "yes"
+            | get package.metadata.docs
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "yes");
+}
+
+#[test]
+fn insert_adds_a_top_level_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | insert license "MIT"
+            | get license
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "MIT");
+}
+
+#[test]
+fn insert_reports_a_path_through_a_non_row_value() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | insert package.name.first "nu"
+        "#
+    ));
+
+    assert!(actual.contains("Expected row, found string"));
+}
@@ -54,3 +54,63 @@ fn gets_last_row_when_no_amount_given() {
         assert_eq!(actual, "1");
     })
 }
+
+#[test]
+fn gets_the_final_row_of_five_by_default() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | last
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "5");
+}
+
+#[test]
+fn gets_the_final_rows_of_five_in_order() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | last 3
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[3,4,5]");
+}
+
+#[test]
+fn gets_every_row_when_asking_for_more_than_there_are() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 5]
+            | last 9
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3,4,5]");
+}
+
+#[test]
+fn gets_every_row_when_asking_for_far_more_than_there_are() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | last 100000000000
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3]");
+}
@@ -0,0 +1,96 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn merges_two_tables_of_equal_length_row_by_row() {
+    Playground::setup("merge_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.json",
+            r#"
+                {
+                    "amigos": [
+                        {"name":   "Yehuda", "luck": {"rusty_luck": 1}},
+                        {"name": "Jonathan", "luck": {"rusty_luck": 2}},
+                        {"name":   "Andres", "luck": {"rusty_luck": 3}}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.json
+                | get amigos
+                | merge { $it.luck }
+                | get rusty_luck
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, "[1,2,3]");
+    })
+}
+
+#[test]
+fn merges_two_rows_with_the_block_winning_overlapping_keys() {
+    Playground::setup("merge_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "package.json",
+            r#"
+                {
+                    "name": "nu",
+                    "version": "0.7",
+                    "release": {"version": "0.8", "edition": "2018"}
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open package.json
+                | merge { $it.release }
+                | reject release
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"{"name":"nu","version":"0.8","edition":"2018"}"#);
+    })
+}
+
+#[test]
+fn passes_input_rows_past_the_end_of_the_block_output_through_unchanged() {
+    Playground::setup("merge_test_3", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_amigos.json",
+            r#"
+                {
+                    "amigos": [
+                        {"name":   "Yehuda", "luck": {"rusty_luck": 1}},
+                        {"name": "Jonathan"},
+                        {"name":   "Andres"}
+                    ]
+                }
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_amigos.json
+                | get amigos
+                | merge { $it.luck }
+                | reject luck
+                | to-json
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            r#"[{"name":"Yehuda","rusty_luck":1},{"name":"Jonathan"},{"name":"Andres"}]"#
+        );
+    })
+}
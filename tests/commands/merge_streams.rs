@@ -0,0 +1,15 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn appends_the_output_of_each_block_in_order() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            echo [1 2]
+            | merge-streams { 3 } { 4 }
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3,4]");
+}
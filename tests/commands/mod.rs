@@ -2,7 +2,10 @@ mod append;
 mod cd;
 mod compact;
 mod cp;
+mod date_diff;
+mod date_format;
 mod default;
+mod echo;
 mod edit;
 mod enter;
 mod first;
@@ -14,17 +17,28 @@ mod insert;
 mod last;
 mod lines;
 mod ls;
+mod merge;
+mod merge_streams;
 mod mkdir;
 mod mv;
+mod nth;
 mod open;
 mod parse;
+mod pivot_longer;
+mod pivot_wider;
 mod prepend;
 mod range;
+mod reject;
+mod rename;
 mod reverse;
 mod rm;
 mod save;
+mod skip_while;
 mod sort_by;
 mod split_by;
 mod split_column;
+mod str_join;
+mod take_while;
+mod to_array;
 mod where_;
 mod wrap;
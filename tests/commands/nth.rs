@@ -1,3 +1,7 @@
+use nu_test_support::fs::Stub::EmptyFile;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
 #[test]
 fn selects_a_row() {
     Playground::setup("nth_test_1", |dirs, sandbox| {
@@ -36,4 +40,103 @@ fn selects_many_rows() {
 
         assert_eq!(actual, "2");
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn selects_rows_in_the_order_requested() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d e]
+            | nth 4 2 0
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["e","c","a"]"#);
+}
+
+#[test]
+fn selects_sorted_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c d e]
+            | nth 0 2 4
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["a","c","e"]"#);
+}
+
+#[test]
+fn selects_a_row_once_per_duplicate_index_and_skips_missing_ones() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | nth 9 1 1
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["b","b"]"#);
+}
+
+#[test]
+fn selects_the_last_row_with_minus_one() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | nth -1
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "c");
+}
+
+#[test]
+fn selects_nothing_with_minus_one_on_an_empty_input() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | nth -1
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn inverts_the_selection() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | nth --invert 1
+            | to-json
+        "#
+    ));
+
+    assert_eq!(actual, r#"["a","c"]"#);
+}
+
+#[test]
+fn inverts_a_selection_with_negative_indices() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [a b c]
+            | nth --invert -1 0
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "b");
+}
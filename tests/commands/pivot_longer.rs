@@ -0,0 +1,53 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn gathers_columns_into_rows() {
+    Playground::setup("pivot_longer_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "commits.csv",
+            r#"
+                author,q1,q2
+                Andrés,10,20
+                Jonathan,,30
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open commits.csv
+                | pivot-longer q1 q2 --key quarter --value count
+                | nth 1
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"{"author":"Andrés","quarter":"q2","count":"20"}"#);
+    })
+}
+
+#[test]
+fn names_new_columns_key_and_value_by_default() {
+    Playground::setup("pivot_longer_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "commits.csv",
+            r#"
+                author,total
+                Andrés,30
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open commits.csv
+                | pivot-longer total
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"{"author":"Andrés","key":"total","value":"30"}"#);
+    })
+}
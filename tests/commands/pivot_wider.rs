@@ -0,0 +1,57 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn spreads_keys_into_columns() {
+    Playground::setup("pivot_wider_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "commits.csv",
+            r#"
+                author,year,count
+                Andrés,2018,10
+                Andrés,2019,20
+                Jonathan,2019,30
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open commits.csv
+                | pivot-wider year count
+                | to-json
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            r#"[{"author":"Andrés","2018":"10","2019":"20"},{"author":"Jonathan","2019":"30"}]"#
+        );
+    })
+}
+
+#[test]
+fn collects_repeated_keys_into_a_table() {
+    Playground::setup("pivot_wider_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "commits.csv",
+            r#"
+                author,year,count
+                Andrés,2019,10
+                Andrés,2019,20
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open commits.csv
+                | pivot-wider year count --collect
+                | to-json
+            "#
+        ));
+
+        assert_eq!(actual, r#"{"author":"Andrés","2019":["10","20"]}"#);
+    })
+}
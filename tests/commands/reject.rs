@@ -0,0 +1,76 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn rejects_a_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open caco3_plastics.csv
+            | first 1
+            | reject origin
+            | pivot
+            | get Column0
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"["importer","shipper","tariff_item","name","shipped_at","arrived_at","net_weight","fob_price","cif_price","cif_per_net_weight"]"#
+    );
+}
+
+#[test]
+fn rejects_columns_keeping_the_order_of_the_rest() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open caco3_plastics.csv
+            | first 1
+            | reject shipped_at importer fob_price
+            | pivot
+            | get Column0
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(
+        actual,
+        r#"["shipper","tariff_item","name","origin","arrived_at","net_weight","cif_price","cif_per_net_weight"]"#
+    );
+}
+
+#[test]
+fn rejects_a_nested_column_path() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | reject dev-dependencies.pretty_assertions
+            | get dev-dependencies
+            | pivot
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn rejecting_a_missing_column_keeps_the_row() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open cargo_sample.toml
+            | get package
+            | reject not_a_column
+            | get name
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "nu");
+}
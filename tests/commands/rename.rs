@@ -0,0 +1,49 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn renames_a_middle_column_in_place() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open caco3_plastics.csv
+            | first 1
+            | pick importer shipper tariff_item
+            | rename shipper exporter
+            | pivot
+            | get Column0
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, r#"["importer","exporter","tariff_item"]"#);
+}
+
+#[test]
+fn keeps_the_value_of_the_renamed_column() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open caco3_plastics.csv
+            | first 1
+            | rename shipper exporter
+            | get exporter
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "S A REVERTE");
+}
+
+#[test]
+fn errors_naming_a_missing_column() {
+    let actual = nu_error!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open caco3_plastics.csv
+            | rename not_a_column exporter
+        "#
+    ));
+
+    assert!(actual.contains("Could not find column not_a_column"));
+}
@@ -1,4 +1,4 @@
-use nu_test_support::nu;
+use nu_test_support::{nu, pipeline};
 
 #[test]
 fn can_get_reverse_first() {
@@ -9,3 +9,33 @@ fn can_get_reverse_first() {
 
     assert_eq!(actual, "utf16.ini");
 }
+
+#[test]
+fn reverses_the_order_of_the_rows() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | reverse
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[3,2,1]");
+}
+
+#[test]
+fn reversing_nothing_gives_nothing() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | reverse
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
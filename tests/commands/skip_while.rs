@@ -0,0 +1,44 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn skips_rows_while_the_condition_holds() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 1]
+            | skip-while { $it < 3 }
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[3,4,1]");
+}
+
+#[test]
+fn keeps_every_row_when_the_first_row_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [5 1 2]
+            | skip-while { $it < 3 }
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[5,1,2]");
+}
+
+#[test]
+fn reports_a_condition_that_fails_to_evaluate() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | skip-while { $it.name < 3 }
+        "#
+    ));
+
+    assert!(actual.contains("Expected row or table, found integer"));
+}
@@ -0,0 +1,69 @@
+use nu_test_support::fs::Stub::FileWithContentToBeTrimmed;
+use nu_test_support::playground::Playground;
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn joins_columns_into_a_new_column() {
+    Playground::setup("str_join_test_1", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name,rusty_at
+                Andrés,Robalino,10/11/2013
+                Jonathan,Turner,10/12/2013
+                Yehuda,Katz,10/11/2013
+            "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | str-join first_name last_name --into name --separator " "
+                | nth 1
+                | get name
+                | echo $it
+            "#
+        ));
+
+        assert_eq!(actual, "Jonathan Turner");
+    })
+}
+
+#[test]
+fn errors_on_missing_columns_when_strict() {
+    Playground::setup("str_join_test_2", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContentToBeTrimmed(
+            "los_tres_caballeros.csv",
+            r#"
+                first_name,last_name
+                Andrés,Robalino
+            "#,
+        )]);
+
+        let actual = nu_error!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                open los_tres_caballeros.csv
+                | str-join first_name middle_name --into name --strict
+            "#
+        ));
+
+        assert!(actual.contains("Unknown column"));
+    })
+}
+
+#[test]
+fn joins_the_input_values_when_no_columns_are_given() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo a b c
+            | str-join --into letters --separator ","
+            | get letters
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "a,b,c");
+}
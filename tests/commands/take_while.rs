@@ -0,0 +1,44 @@
+use nu_test_support::{nu, nu_error, pipeline};
+
+#[test]
+fn takes_rows_while_the_condition_holds() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3 4 1]
+            | take-while { $it < 3 }
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2]");
+}
+
+#[test]
+fn takes_nothing_when_the_first_row_fails() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [5 1 2]
+            | take-while { $it < 3 }
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "0");
+}
+
+#[test]
+fn reports_a_condition_that_fails_to_evaluate() {
+    let actual = nu_error!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | take-while { $it.name < 3 }
+        "#
+    ));
+
+    assert!(actual.contains("Expected row or table, found integer"));
+}
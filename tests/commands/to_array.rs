@@ -0,0 +1,47 @@
+use nu_test_support::{nu, pipeline};
+
+#[test]
+fn collects_rows_into_a_single_table() {
+    let actual = nu!(
+        cwd: "tests/fixtures/formats", pipeline(
+        r#"
+            open caco3_plastics.csv
+            | first 3
+            | to-array
+            | count
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "1");
+}
+
+#[test]
+fn keeps_every_row_in_the_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo [1 2 3]
+            | to-array
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[1,2,3]");
+}
+
+#[test]
+fn collects_an_empty_stream_into_an_empty_table() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo []
+            | to-array
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[]");
+}
@@ -17,3 +17,24 @@ fn table_to_sqlite_and_back_into_table() {
 
     assert_eq!(actual, "hello");
 }
+
+#[test]
+fn durations_to_sqlite_as_nanoseconds() {
+    let actual = nu!(
+        cwd: ".", pipeline(
+        r#"
+            echo 1s 2s
+            | wrap wait
+            | wrap table_values
+            | insert table_name durations
+            | to-sqlite
+            | from-sqlite
+            | get table_values
+            | get wait
+            | to-json
+            | echo $it
+        "#
+    ));
+
+    assert_eq!(actual, "[1000000000,2000000000]");
+}
@@ -9,7 +9,7 @@ fn can_only_apply_one() {
         "open caco3_plastics.csv | first 1 | str origin --downcase --upcase"
     );
 
-    assert!(actual.contains(r#"--downcase|--upcase|--to-int|--substring "start,end"|--replace|--find-replace [pattern replacement]]"#));
+    assert!(actual.contains(r#"--downcase|--upcase|--to-int|--to-date-time [--format format]|--substring "start,end"|--replace|--find-replace [pattern replacement]|--find-replace-all [pattern replacement ...] [--regex]]"#));
 }
 
 #[test]
@@ -164,3 +164,28 @@ fn find_and_replaces_without_passing_field() {
         assert_eq!(actual, "1-800-5289");
     })
 }
+
+#[test]
+fn find_and_replaces_all_rules_in_order() {
+    Playground::setup("plugin_str_test_7", |dirs, sandbox| {
+        sandbox.with_files(vec![FileWithContent(
+            "sample.toml",
+            r#"
+                     [fortune.teller]
+                     phone = "1-800-KATZ"
+                 "#,
+        )]);
+
+        let actual = nu!(
+            cwd: dirs.test(), pipeline(
+            r#"
+                 open sample.toml
+                 | str fortune.teller.phone --find-replace-all [KATZ "5289" "1-800" "1-888"]
+                 | get fortune.teller.phone
+                 | echo $it
+             "#
+        ));
+
+        assert_eq!(actual, "1-888-5289");
+    })
+}